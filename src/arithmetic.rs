@@ -0,0 +1,662 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Arithmetic operations on decimals
+
+use crate::bid128::{
+  bid128_from_value, Bid128, BID128_BIAS, BID128_EMAX, BID128_NAX_DIGITS, MAX_COEFFICIENT, MAX_EXPONENT,
+};
+use crate::bignum::BigUint;
+use crate::recognizer::{Rounding, Value};
+use crate::Status;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Minimum exponent of the coefficient, the exponent of the smallest subnormal number.
+const MIN_EXPONENT: i64 = -(BID128_BIAS as i64);
+
+/// Minimum adjusted exponent of normal numbers.
+const EMIN: i64 = 1 - BID128_EMAX as i64;
+
+impl Bid128 {
+  /// Adds two decimals, with rounding mode.
+  ///
+  /// The exact sum is rounded to 34 digits, exact results have the smaller exponent of both operands.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("1.25");
+  /// let (b, _) = bid128_from_string("0.750");
+  /// let (sum, status) = a.add_rnd(&b, Rounding::ToNearest);
+  /// assert_eq!("2.000", sum.to_string());
+  /// assert!(status.is_empty());
+  ///
+  /// let (a, _) = bid128_from_string("1e34");
+  /// let (b, _) = bid128_from_string("0.5");
+  /// let (sum, status) = a.add_rnd(&b, Rounding::Up);
+  /// assert_eq!("1.000000000000000000000000000000001E+34", sum.to_string());
+  /// assert!(status.is_inexact());
+  /// ```
+  pub fn add_rnd(&self, other: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    add(self.unpack(), other.unpack(), false, rnd)
+  }
+
+  /// Subtracts other decimal from this decimal, with rounding mode.
+  ///
+  /// The difference is computed like the sum in [Bid128::add_rnd], with the sign of other decimal inverted.
+  pub fn sub_rnd(&self, other: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    add(self.unpack(), other.unpack(), true, rnd)
+  }
+
+  /// Multiplies two decimals, with rounding mode.
+  ///
+  /// The exact product of coefficients is rounded to 34 digits,
+  /// exact results have the exponent equal to the sum of exponents of both operands.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (price, _) = bid128_from_string("12.99");
+  /// let (quantity, _) = bid128_from_string("3");
+  /// let (total, status) = price.mul_rnd(&quantity, Rounding::ToNearest);
+  /// assert_eq!("38.97", total.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn mul_rnd(&self, other: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Infinity(_), Value::Finite(_, 0, _, _)) | (Value::Finite(_, 0, _, _), Value::Infinity(_)) => invalid(),
+      (Value::Infinity(sign_a), Value::Infinity(sign_b) | Value::Finite(sign_b, ..))
+      | (Value::Finite(sign_a, ..), Value::Infinity(sign_b)) => infinity(sign_a ^ sign_b),
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+        let product = BigUint::from_u128(coefficient_a).mul(&BigUint::from_u128(coefficient_b));
+        round_result(
+          sign_a ^ sign_b,
+          &product,
+          exponent_a as i64 + exponent_b as i64,
+          false,
+          rnd,
+        )
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Multiplies this decimal by other decimal and adds the third decimal, with rounding mode.
+  ///
+  /// The result of `self * multiplier + addend` is computed exactly and rounded once to 34 digits.
+  /// Multiplying zero by infinity is invalid, even when the addend is a quiet NaN.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("1.000000000000000000000000000000001");
+  /// let (b, _) = bid128_from_string("-1");
+  /// let (result, status) = a.fma_rnd(&a, &b, Rounding::ToNearest);
+  /// assert_eq!("2.000000000000000000000000000000001E-33", result.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn fma_rnd(&self, multiplier: &Bid128, addend: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    let (a, b, c) = (self.unpack(), multiplier.unpack(), addend.unpack());
+    if matches!(a, Value::NaN(..)) || matches!(b, Value::NaN(..)) {
+      return propagate_nan(&[&a, &b, &c]).unwrap_or_else(invalid);
+    }
+    match (a, b) {
+      (Value::Infinity(_), Value::Finite(_, 0, _, _)) | (Value::Finite(_, 0, _, _), Value::Infinity(_)) => invalid(),
+      (Value::Infinity(sign_a), Value::Infinity(sign_b) | Value::Finite(sign_b, ..))
+      | (Value::Finite(sign_a, ..), Value::Infinity(sign_b)) => add(Value::Infinity(sign_a ^ sign_b), c, false, rnd),
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+        match c {
+          Value::Finite(sign_c, coefficient_c, exponent_c, _) => {
+            let product = BigUint::from_u128(coefficient_a).mul(&BigUint::from_u128(coefficient_b));
+            add_finite(
+              (sign_a ^ sign_b, product, exponent_a as i64 + exponent_b as i64),
+              (sign_c, BigUint::from_u128(coefficient_c), exponent_c as i64),
+              rnd,
+            )
+          }
+          Value::Infinity(sign_c) => infinity(sign_c),
+          _ => propagate_nan(&[&c]).unwrap_or_else(invalid),
+        }
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns the square root of this decimal, with rounding mode.
+  ///
+  /// The square root is rounded to 34 digits, exact results have the exponent closest to the half
+  /// of the exponent of this decimal. The square root of negative zero is negative zero,
+  /// the square root of any other negative number is invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("2");
+  /// let (root, status) = a.sqrt_rnd(Rounding::ToNearest);
+  /// assert_eq!("1.414213562373095048801688724209698", root.to_string());
+  /// assert!(status.is_inexact());
+  ///
+  /// let (a, _) = bid128_from_string("1.44");
+  /// let (root, status) = a.sqrt_rnd(Rounding::ToNearest);
+  /// assert_eq!("1.2", root.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn sqrt_rnd(&self, rnd: Rounding) -> (Bid128, Status) {
+    let a = self.unpack();
+    if let Some(result) = propagate_nan(&[&a]) {
+      return result;
+    }
+    match a {
+      Value::Infinity(false) => infinity(false),
+      Value::Finite(sign, 0, exponent, _) => round_result(
+        sign,
+        &BigUint::from_u128(0),
+        (exponent as i64).div_euclid(2),
+        false,
+        rnd,
+      ),
+      Value::Finite(false, coefficient, exponent, _) => {
+        let (exponent, ideal_exponent) = (exponent as i64, (exponent as i64).div_euclid(2));
+        // the square root has at least one digit more than the precision,
+        // the exponent of the scaled coefficient must be even
+        let mut shift = (2 * (BID128_NAX_DIGITS as i64 + 1) - 1 - digits(coefficient)).max(0);
+        if (exponent - shift) % 2 != 0 {
+          shift += 1;
+        }
+        let mut scaled = BigUint::from_u128(coefficient);
+        scaled.mul_pow10(shift as u32);
+        let mut root = scaled.isqrt();
+        let mut exponent = (exponent - shift) / 2;
+        let exact = root.mul(&root) == scaled;
+        if exact {
+          // exact square root is reduced toward the ideal exponent
+          while exponent < ideal_exponent {
+            let mut reduced = root.clone();
+            if reduced.div_rem_small(10) != 0 {
+              break;
+            }
+            root = reduced;
+            exponent += 1;
+          }
+        }
+        round_result(false, &root, exponent, !exact, rnd)
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Divides this decimal by other decimal, with rounding mode.
+  ///
+  /// The quotient is rounded to 34 digits, exact results have the exponent closest to the difference
+  /// of exponents of both operands. Division of a finite nonzero number by zero returns an infinity
+  /// and reports division by zero, dividing zero by zero or infinity by infinity is invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("1");
+  /// let (b, _) = bid128_from_string("3");
+  /// let (quotient, status) = a.div_rnd(&b, Rounding::ToNearest);
+  /// assert_eq!("0.3333333333333333333333333333333333", quotient.to_string());
+  /// assert!(status.is_inexact());
+  ///
+  /// let (a, _) = bid128_from_string("2.40");
+  /// let (b, _) = bid128_from_string("2");
+  /// let (quotient, status) = a.div_rnd(&b, Rounding::ToNearest);
+  /// assert_eq!("1.20", quotient.to_string());
+  /// assert!(status.is_empty());
+  ///
+  /// let (a, _) = bid128_from_string("-1");
+  /// let (b, _) = bid128_from_string("0");
+  /// let (quotient, status) = a.div_rnd(&b, Rounding::ToNearest);
+  /// assert_eq!("-Infinity", quotient.to_string());
+  /// assert!(status.is_zero_divide());
+  /// ```
+  pub fn div_rnd(&self, other: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Infinity(_), Value::Infinity(_)) | (Value::Finite(_, 0, _, _), Value::Finite(_, 0, _, _)) => invalid(),
+      (Value::Infinity(sign_a), Value::Finite(sign_b, ..)) => infinity(sign_a ^ sign_b),
+      (Value::Finite(sign_a, ..), Value::Infinity(sign_b)) => {
+        round_result(sign_a ^ sign_b, &BigUint::from_u128(0), MIN_EXPONENT, false, rnd)
+      }
+      (Value::Finite(sign_a, _, _, _), Value::Finite(sign_b, 0, _, _)) => {
+        (infinity(sign_a ^ sign_b).0, Status::ZERO_DIVIDE)
+      }
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+        let ideal_exponent = exponent_a as i64 - exponent_b as i64;
+        if coefficient_a == 0 {
+          return round_result(sign_a ^ sign_b, &BigUint::from_u128(0), ideal_exponent, false, rnd);
+        }
+        // the quotient has at least one digit more than the precision
+        let shift = BID128_NAX_DIGITS as i64 + 1 + digits(coefficient_b) - digits(coefficient_a);
+        let mut dividend = BigUint::from_u128(coefficient_a);
+        dividend.mul_pow10(shift as u32);
+        let (mut quotient, remainder) = dividend.div_rem(&BigUint::from_u128(coefficient_b));
+        let mut exponent = ideal_exponent - shift;
+        if remainder.is_zero() {
+          // exact quotient is reduced toward the ideal exponent
+          while exponent < ideal_exponent {
+            let mut reduced = quotient.clone();
+            if reduced.div_rem_small(10) != 0 {
+              break;
+            }
+            quotient = reduced;
+            exponent += 1;
+          }
+        }
+        round_result(sign_a ^ sign_b, &quotient, exponent, !remainder.is_zero(), rnd)
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns the remainder of dividing this decimal by other decimal, as defined by IEEE 754.
+  ///
+  /// The remainder is `self - n * other`, where `n` is the integer nearest to the exact quotient,
+  /// ties to even. The remainder is always exact and has the smaller exponent of both operands,
+  /// zero remainder has the sign of this decimal. The remainder of an infinity or by zero is invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (a, _) = bid128_from_string("10");
+  /// let (b, _) = bid128_from_string("3");
+  /// let (remainder, status) = a.remainder(&b);
+  /// assert_eq!("1", remainder.to_string());
+  /// assert!(status.is_empty());
+  ///
+  /// let (a, _) = bid128_from_string("11");
+  /// let (remainder, _) = a.remainder(&b);
+  /// assert_eq!("-1", remainder.to_string());
+  /// ```
+  pub fn remainder(&self, other: &Bid128) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Infinity(_)) => {
+        exact(sign_a, coefficient_a, exponent_a)
+      }
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(_, coefficient_b, exponent_b, _))
+        if coefficient_b != 0 =>
+      {
+        let (quotient, mut remainder, exponent) =
+          divide_aligned((coefficient_a, exponent_a as i64), (coefficient_b, exponent_b as i64));
+        let mut divisor = BigUint::from_u128(coefficient_b);
+        divisor.mul_pow10((exponent_b as i64 - exponent) as u32);
+        let mut twice = remainder.clone();
+        twice.mul_small(2);
+        // the quotient is rounded up when the remainder exceeds half of the divisor
+        let sign = if twice > divisor || (twice == divisor && quotient.low_u64() & 1 == 1) {
+          divisor.sub(&remainder);
+          remainder = divisor;
+          !sign_a
+        } else {
+          sign_a
+        };
+        round_result(sign, &remainder, exponent, false, Rounding::ToNearest)
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns the integer part of dividing this decimal by other decimal, truncated toward zero.
+  ///
+  /// The result has the exponent zero. When the integer part has more than 34 digits
+  /// the division is impossible and reported as invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (a, _) = bid128_from_string("-7.5");
+  /// let (b, _) = bid128_from_string("2");
+  /// let (quotient, status) = a.divide_integer(&b);
+  /// assert_eq!("-3", quotient.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn divide_integer(&self, other: &Bid128) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Infinity(_), Value::Infinity(_)) | (Value::Finite(_, 0, _, _), Value::Finite(_, 0, _, _)) => invalid(),
+      (Value::Infinity(sign_a), Value::Finite(sign_b, ..)) => infinity(sign_a ^ sign_b),
+      (Value::Finite(sign_a, ..), Value::Infinity(sign_b)) => exact(sign_a ^ sign_b, 0, 0),
+      (Value::Finite(sign_a, _, _, _), Value::Finite(sign_b, 0, _, _)) => {
+        (infinity(sign_a ^ sign_b).0, Status::ZERO_DIVIDE)
+      }
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+        match divide_truncated((coefficient_a, exponent_a as i64), (coefficient_b, exponent_b as i64)) {
+          Some((quotient, _, _)) => round_result(sign_a ^ sign_b, &quotient, 0, false, Rounding::ToNearest),
+          None => invalid(),
+        }
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns the remainder of dividing this decimal by other decimal, with the quotient truncated toward zero.
+  ///
+  /// The remainder is `self - n * other`, where `n` is the result of [Bid128::divide_integer].
+  /// The remainder is exact, has the sign of this decimal and the smaller exponent of both operands.
+  /// The remainder of an infinity or by zero is invalid, as well as when the division is impossible.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (a, _) = bid128_from_string("-7.5");
+  /// let (b, _) = bid128_from_string("2");
+  /// let (remainder, status) = a.rem(&b);
+  /// assert_eq!("-1.5", remainder.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn rem(&self, other: &Bid128) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Infinity(_)) => {
+        exact(sign_a, coefficient_a, exponent_a)
+      }
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(_, coefficient_b, exponent_b, _))
+        if coefficient_b != 0 =>
+      {
+        match divide_truncated((coefficient_a, exponent_a as i64), (coefficient_b, exponent_b as i64)) {
+          Some((_, remainder, exponent)) => round_result(sign_a, &remainder, exponent, false, Rounding::ToNearest),
+          None => invalid(),
+        }
+      }
+      _ => invalid(),
+    }
+  }
+}
+
+impl Add for Bid128 {
+  type Output = Bid128;
+
+  /// Adds two decimals, rounding to nearest, ties to even.
+  fn add(self, rhs: Self) -> Self::Output {
+    self.add_rnd(&rhs, Rounding::ToNearest).0
+  }
+}
+
+impl Div for Bid128 {
+  type Output = Bid128;
+
+  /// Divides two decimals, rounding to nearest, ties to even.
+  fn div(self, rhs: Self) -> Self::Output {
+    self.div_rnd(&rhs, Rounding::ToNearest).0
+  }
+}
+
+impl Mul for Bid128 {
+  type Output = Bid128;
+
+  /// Multiplies two decimals, rounding to nearest, ties to even.
+  fn mul(self, rhs: Self) -> Self::Output {
+    self.mul_rnd(&rhs, Rounding::ToNearest).0
+  }
+}
+
+impl Sub for Bid128 {
+  type Output = Bid128;
+
+  /// Subtracts two decimals, rounding to nearest, ties to even.
+  fn sub(self, rhs: Self) -> Self::Output {
+    self.sub_rnd(&rhs, Rounding::ToNearest).0
+  }
+}
+
+/// Adds two unpacked decimals, the sign of the second operand is inverted when `negate` is `true`.
+fn add(a: Value, b: Value, negate: bool, rnd: Rounding) -> (Bid128, Status) {
+  if let Some(result) = propagate_nan(&[&a, &b]) {
+    return result;
+  }
+  match (a, b) {
+    (Value::Infinity(sign_a), Value::Infinity(sign_b)) if sign_a != sign_b ^ negate => invalid(),
+    (Value::Infinity(sign), _) => infinity(sign),
+    (_, Value::Infinity(sign)) => infinity(sign ^ negate),
+    (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+      add_finite(
+        (sign_a, BigUint::from_u128(coefficient_a), exponent_a as i64),
+        (sign_b ^ negate, BigUint::from_u128(coefficient_b), exponent_b as i64),
+        rnd,
+      )
+    }
+    _ => invalid(),
+  }
+}
+
+/// Adds two finite numbers given as sign, coefficient and exponent.
+///
+/// Coefficients may have more digits than the precision, like the exact product in fused multiply-add.
+fn add_finite(a: (bool, BigUint, i64), b: (bool, BigUint, i64), rnd: Rounding) -> (Bid128, Status) {
+  // the first operand has the greater exponent
+  let ((sign_a, mut coefficient_a, exponent_a), (sign_b, mut coefficient_b, mut exponent_b)) =
+    if a.2 >= b.2 { (a, b) } else { (b, a) };
+  if !coefficient_a.is_zero() && !coefficient_b.is_zero() {
+    // the operand far below the precision and the last digit of the other operand
+    // affects only the rounding, so it is replaced with a smaller number rounded the same way
+    let limit =
+      (exponent_a + coefficient_a.decimal_digits() as i64 - 1 - (BID128_NAX_DIGITS as i64 + 3)).min(exponent_a - 1);
+    if exponent_b + coefficient_b.decimal_digits() as i64 - 1 < limit {
+      coefficient_b = BigUint::from_u128(1);
+      exponent_b = limit;
+    }
+  }
+  let mut shift = exponent_a - exponent_b;
+  if !coefficient_a.is_zero() && coefficient_b.is_zero() {
+    // the exact result needs no more digits than the precision
+    shift = shift.min((BID128_NAX_DIGITS as i64 - coefficient_a.decimal_digits() as i64).max(0));
+  }
+  coefficient_a.mul_pow10(shift as u32);
+  let exponent = exponent_a - shift;
+  let (sign, coefficient) = if sign_a == sign_b {
+    coefficient_a.add(&coefficient_b);
+    (sign_a, coefficient_a)
+  } else if coefficient_a >= coefficient_b {
+    coefficient_a.sub(&coefficient_b);
+    (sign_a, coefficient_a)
+  } else {
+    coefficient_b.sub(&coefficient_a);
+    (sign_b, coefficient_b)
+  };
+  // exact zero sum of operands with opposite signs is negative only when rounding down
+  let sign = if coefficient.is_zero() && sign_a != sign_b {
+    rnd == Rounding::Down
+  } else {
+    sign
+  };
+  round_result(sign, &coefficient, exponent, false, rnd)
+}
+
+/// Divides finite numbers given as coefficient and exponent, aligned to the smaller exponent of both,
+/// returns the integer quotient truncated toward zero, the remainder and the exponent of the remainder.
+fn divide_aligned(a: (u128, i64), b: (u128, i64)) -> (BigUint, BigUint, i64) {
+  let exponent = a.1.min(b.1);
+  let mut dividend = BigUint::from_u128(a.0);
+  dividend.mul_pow10((a.1 - exponent) as u32);
+  let mut divisor = BigUint::from_u128(b.0);
+  divisor.mul_pow10((b.1 - exponent) as u32);
+  let (quotient, remainder) = dividend.div_rem(&divisor);
+  (quotient, remainder, exponent)
+}
+
+/// Divides finite numbers like [divide_aligned], returns `None` when the quotient has more than 34 digits.
+fn divide_truncated(a: (u128, i64), b: (u128, i64)) -> Option<(BigUint, BigUint, i64)> {
+  if a.0 != 0 {
+    let difference = adjusted(a.0, a.1) - adjusted(b.0, b.1);
+    if difference < 0 {
+      // the dividend is less than the divisor, so the remainder is the aligned dividend
+      let exponent = a.1.min(b.1);
+      let mut remainder = BigUint::from_u128(a.0);
+      remainder.mul_pow10((a.1 - exponent) as u32);
+      return Some((BigUint::from_u128(0), remainder, exponent));
+    }
+    if difference > BID128_NAX_DIGITS as i64 {
+      return None;
+    }
+  }
+  let (quotient, remainder, exponent) = divide_aligned(a, b);
+  (quotient <= BigUint::from_u128(MAX_COEFFICIENT)).then_some((quotient, remainder, exponent))
+}
+
+/// Rounds the exact result `coefficient * 10^exponent` to 34 digits and encodes it,
+/// returns the status flags of rounding, including overflow and underflow.
+///
+/// Exact results keep the exponent when possible, exponents out of range are clamped.
+/// Nonzero digits below the last digit of the coefficient are indicated by `sticky`,
+/// in such case the coefficient must have more digits than the precision.
+pub(crate) fn round_result(
+  sign: bool,
+  coefficient: &BigUint,
+  exponent: i64,
+  sticky: bool,
+  rnd: Rounding,
+) -> (Bid128, Status) {
+  if coefficient.is_zero() {
+    let exponent = exponent.clamp(MIN_EXPONENT, MAX_EXPONENT as i64) as i32;
+    return bid128_from_value(Value::Finite(sign, 0, exponent, Status::NONE));
+  }
+  let count = coefficient.decimal_digits() as i64;
+  let adjusted_exponent = exponent + count - 1;
+  // number of digits not fitting in the precision or below the smallest subnormal number
+  let dropped = (count - BID128_NAX_DIGITS as i64).max(MIN_EXPONENT - exponent).max(0);
+  let (mut value, round, sticky) = if dropped == 0 {
+    (coefficient.low_u128(), 0, sticky)
+  } else if dropped <= count {
+    // the highest dropped digit decides the rounding, the digits below only affect the sticky bit
+    let (mut quotient, rest) = coefficient.div_rem(&BigUint::pow10(dropped as u32 - 1));
+    let round = quotient.div_rem_small(10);
+    (quotient.low_u128(), round, sticky || !rest.is_zero())
+  } else {
+    (0, 0, true)
+  };
+  let mut exponent = exponent + dropped;
+  let mut flags = Status::NONE;
+  if round > 0 || sticky {
+    flags |= Status::INEXACT;
+    // tininess is detected before rounding
+    if adjusted_exponent < EMIN {
+      flags |= Status::UNDERFLOW;
+    }
+  }
+  // nonzero digits below the round digit make the dropped part greater than the half
+  let half = round.cmp(&5).then(sticky.cmp(&false));
+  if rnd.carry(sign, value & 1 == 1, half, round > 0 || sticky) {
+    value += 1;
+    // rounding up may produce one digit more than allowed
+    if value > MAX_COEFFICIENT {
+      value /= 10;
+      exponent += 1;
+    }
+  }
+  if value != 0 && adjusted(value, exponent) > BID128_EMAX as i64 {
+    return overflow(sign, rnd);
+  }
+  let (result, _) = bid128_from_value(Value::Finite(sign, value, exponent as i32, Status::NONE));
+  (result, flags)
+}
+
+/// Returns the result of an overflow, either infinity or the largest finite number depending on rounding.
+fn overflow(sign: bool, rnd: Rounding) -> (Bid128, Status) {
+  let to_infinity = match rnd {
+    Rounding::ToNearest | Rounding::TiesAway => true,
+    Rounding::Down => sign,
+    Rounding::Up => !sign,
+    Rounding::ToZero => false,
+  };
+  let value = if to_infinity {
+    Value::Infinity(sign)
+  } else {
+    Value::Finite(sign, MAX_COEFFICIENT, MAX_EXPONENT, Status::NONE)
+  };
+  (bid128_from_value(value).0, Status::OVERFLOW | Status::INEXACT)
+}
+
+/// Returns the quiet NaN propagated from operands, signaling NaNs have precedence and are reported as invalid.
+pub(crate) fn propagate_nan(operands: &[&Value]) -> Option<(Bid128, Status)> {
+  let nan = |signaling: bool| {
+    operands.iter().find_map(|operand| match operand {
+      Value::NaN(sign, s, payload) if *s == signaling => Some(bid128_from_value(Value::NaN(*sign, false, *payload)).0),
+      _ => None,
+    })
+  };
+  if let Some(result) = nan(true) {
+    return Some((result, Status::INVALID));
+  }
+  nan(false).map(|result| (result, Status::NONE))
+}
+
+/// Returns the default quiet NaN reported as invalid.
+pub(crate) fn invalid() -> (Bid128, Status) {
+  (bid128_from_value(Value::NaN(false, false, 0)).0, Status::INVALID)
+}
+
+/// Returns the finite number given as sign, coefficient and exponent, encoded without rounding.
+fn exact(sign: bool, coefficient: u128, exponent: i32) -> (Bid128, Status) {
+  bid128_from_value(Value::Finite(sign, coefficient, exponent, Status::NONE))
+}
+
+/// Returns the signed infinity.
+pub(crate) fn infinity(sign: bool) -> (Bid128, Status) {
+  bid128_from_value(Value::Infinity(sign))
+}
+
+/// Returns the number of digits of the coefficient, zero has one digit.
+fn digits(coefficient: u128) -> i64 {
+  coefficient.checked_ilog10().unwrap_or(0) as i64 + 1
+}
+
+/// Returns the exponent of the most significant digit.
+fn adjusted(coefficient: u128, exponent: i64) -> i64 {
+  exponent + digits(coefficient) - 1
+}
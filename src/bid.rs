@@ -0,0 +1,195 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Encoder for floating-point decimals in binary integer decimal format.
+
+use crate::recognizer::Value;
+use crate::Status;
+
+/// Parameters of a binary integer decimal interchange format.
+pub(crate) struct BidFormat {
+  /// Total number of bits in the encoding.
+  pub(crate) bits: u32,
+  /// Number of bits of the biased exponent.
+  pub(crate) exponent_bits: u32,
+  /// Maximum number of digits in the coefficient.
+  pub(crate) digits: i32,
+  /// Exponent bias.
+  pub(crate) bias: i32,
+  /// Maximum adjusted exponent.
+  pub(crate) emax: i32,
+}
+
+/// Parameters of decimal32 format.
+pub(crate) const BID32: BidFormat = BidFormat {
+  bits: 32,
+  exponent_bits: 8,
+  digits: 7,
+  bias: 101,
+  emax: 96,
+};
+
+/// Parameters of decimal64 format.
+pub(crate) const BID64: BidFormat = BidFormat {
+  bits: 64,
+  exponent_bits: 10,
+  digits: 16,
+  bias: 398,
+  emax: 384,
+};
+
+/// Parameters of decimal128 format.
+pub(crate) const BID128: BidFormat = BidFormat {
+  bits: 128,
+  exponent_bits: 14,
+  digits: 34,
+  bias: 6176,
+  emax: 6144,
+};
+
+impl BidFormat {
+  /// Returns the sign bit.
+  fn sign(&self, sign: bool) -> u128 {
+    (sign as u128) << (self.bits - 1)
+  }
+
+  /// Returns the number of trailing bits holding coefficients encoded without steering bits.
+  fn coefficient_bits(&self) -> u32 {
+    self.bits - 1 - self.exponent_bits
+  }
+
+  /// Returns the largest coefficient.
+  pub(crate) const fn max_coefficient(&self) -> u128 {
+    10_u128.pow(self.digits as u32) - 1
+  }
+
+  /// Returns the largest canonical payload of NaN.
+  pub(crate) const fn max_payload(&self) -> u128 {
+    10_u128.pow(self.digits as u32 - 1) - 1
+  }
+
+  /// Returns the largest exponent of the coefficient treated as an integer.
+  pub(crate) const fn max_exponent(&self) -> i32 {
+    self.emax - self.digits + 1
+  }
+
+  /// Returns the encoding of an infinity.
+  fn infinity(&self, sign: bool) -> u128 {
+    0x1e << (self.bits - 6) | self.sign(sign)
+  }
+
+  /// Returns the encoding of a NaN with zero payload.
+  fn nan(&self, sign: bool, signaling: bool) -> u128 {
+    if signaling {
+      0x3f << (self.bits - 7) | self.sign(sign)
+    } else {
+      0x1f << (self.bits - 6) | self.sign(sign)
+    }
+  }
+
+  /// Returns the encoding of a finite number with biased exponent.
+  fn finite(&self, sign: bool, coefficient: u128, biased_exponent: u128) -> u128 {
+    let width = self.coefficient_bits();
+    if coefficient < 1 << width {
+      coefficient | biased_exponent << width | self.sign(sign)
+    } else {
+      // coefficients not fitting in the trailing bits are encoded after the steering bits
+      let width = width - 2;
+      (coefficient & ((1 << width) - 1)) | biased_exponent << width | 0x3 << (self.bits - 3) | self.sign(sign)
+    }
+  }
+}
+
+/// Encodes the value in the specified format, returns the encoded bits and status flags.
+///
+/// The coefficient of the finite value must not have more digits than the format allows.
+pub(crate) fn bid_from_value(value: Value, format: &BidFormat) -> (u128, Status) {
+  let max_exponent = format.max_exponent();
+  match value {
+    Value::Finite(sign, mut value, mut exponent, status) => {
+      let mut flags = status;
+      let e;
+      if value == 0 {
+        if exponent < -(format.bias + format.digits) {
+          flags |= Status::UNDERFLOW | Status::INEXACT;
+          e = 0;
+        } else if exponent < -format.bias {
+          e = 0;
+        } else if exponent < max_exponent {
+          e = (format.bias + exponent) as u128;
+        } else {
+          e = (format.bias + max_exponent) as u128;
+        }
+      } else {
+        if exponent > max_exponent {
+          // try to normalize before reporting an overflow
+          let max_coefficient = format.max_coefficient();
+          while exponent > max_exponent {
+            let n = value * 10;
+            if n <= max_coefficient {
+              value = n;
+              exponent -= 1;
+            } else {
+              break;
+            }
+          }
+          if exponent > max_exponent {
+            // +inf, overflow, inexact
+            flags |= Status::OVERFLOW | Status::INEXACT;
+            return (format.infinity(sign), flags);
+          }
+        }
+        if exponent < -format.bias {
+          // try to normalize before reporting an underflow
+          while exponent < -format.bias {
+            let r = value % 10;
+            if r == 0 {
+              value /= 10;
+              exponent += 1;
+            } else {
+              break;
+            }
+          }
+          if exponent < -format.bias {
+            //  underflow, inexact
+            flags |= Status::UNDERFLOW | Status::INEXACT;
+            return (format.sign(sign), flags);
+          }
+        }
+        e = (format.bias + exponent) as u128;
+      }
+      (format.finite(sign, value, e), flags)
+    }
+    Value::Infinity(sign) => (format.infinity(sign), Status::NONE),
+    Value::NaN(sign, signaling, payload) => {
+      let mut nan = format.nan(sign, signaling);
+      // non-canonical payloads are replaced with zero
+      if payload <= format.max_payload() {
+        nan |= payload;
+      }
+      (nan, Status::NONE)
+    }
+    Value::Invalid(sign, _) => (format.nan(sign, false), Status::INVALID),
+  }
+}
@@ -24,145 +24,537 @@
 
 //! # Recognizer for 128-bit floating-point decimals.
 
-use crate::recognizer::{recognize, Value, FLAG_INEXACT, FLAG_OVERFLOW, FLAG_UNDERFLOW};
-use crate::Rounding;
+use crate::bid::{bid_from_value, BID128};
+use crate::error::{FromStrError, ParseError, RangePolicy};
+use crate::formatter::{pad_scientific, write_engineering};
+use crate::options::ParseOptions;
+use crate::recognizer::{recognize, Mode, Value};
+use crate::{Rounding, Status};
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
 
 /// 128-bit decimal in binary format.
+#[derive(Copy, Clone)]
 pub struct Bid128 {
   pub w: [u64; 2],
 }
 
-const BID128_BIAS: i32 = 6176;
+pub(crate) const BID128_BIAS: i32 = BID128.bias;
 
-const BID128_EMAX: i32 = 6144;
+pub(crate) const BID128_EMAX: i32 = BID128.emax;
 
-const BID128_NAX_DIGITS: i32 = 34;
+pub(crate) const BID128_NAX_DIGITS: i32 = BID128.digits;
 
 const BID128_SIGN: u64 = 0x8000000000000000;
 
-const MAX_COEFFICIENT: u128 = 9999999999999999999999999999999999;
+const BID128_NAN_MASK: u64 = 0x7c00000000000000;
 
-const MAX_EXPONENT: i32 = BID128_EMAX - BID128_NAX_DIGITS + 1;
+const BID128_SNAN_MASK: u64 = 0x0200000000000000;
 
-const BID128_NAN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0x7c00000000000000],
-};
+const BID128_INF_MASK: u64 = 0x7800000000000000;
 
-const BID128_NEG_NAN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0xfc00000000000000],
-};
+const BID128_STEERING_MASK: u64 = 0x6000000000000000;
 
-const BID128_SNAN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0x7e00000000000000],
-};
+const BID128_COEFFICIENT_MASK: u64 = 0x0001ffffffffffff;
 
-const BID128_NEG_SNAN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0xfe00000000000000],
-};
+const BID128_PAYLOAD_MASK: u64 = 0x00003fffffffffff;
 
-const BID128_INF: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0x7800000000000000],
-};
+pub(crate) const MAX_COEFFICIENT: u128 = BID128.max_coefficient();
 
-const BID128_NEG_INF: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0xf800000000000000],
-};
+pub(crate) const MAX_PAYLOAD: u128 = BID128.max_payload();
 
-const BID128_MIN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0x0000000000000000],
-};
+pub(crate) const MAX_EXPONENT: i32 = BID128.max_exponent();
 
-const BID128_NEG_MIN: Bid128 = Bid128 {
-  w: [0x0000000000000000, 0x8000000000000000],
-};
+const MAX_BIASED_EXPONENT: u64 = 0x2fff;
 
-/// Parses a 128-bit floating-point decimal from text in scientific notation.
-pub fn bid128_from_string(input: &str) -> (Bid128, u32) {
-  bid128_from_string_rnd(input, Rounding::ToNearest)
+const BID128_LARGE_COEFFICIENT: u128 = 1 << 113;
+
+const BID128_LARGE_COEFFICIENT_MASK: u64 = 0x00007fffffffffff;
+
+impl Bid128 {
+  /// Returns the sign, coefficient and exponent of this decimal.
+  ///
+  /// Non-canonical coefficients and payloads are returned as zero.
+  pub(crate) fn unpack(&self) -> Value {
+    let [w0, w1] = self.w;
+    let sign = w1 & BID128_SIGN != 0;
+    if w1 & BID128_NAN_MASK == BID128_NAN_MASK {
+      let mut payload = ((w1 & BID128_PAYLOAD_MASK) as u128) << 64 | w0 as u128;
+      if payload > MAX_PAYLOAD {
+        payload = 0;
+      }
+      return Value::NaN(sign, w1 & BID128_SNAN_MASK != 0, payload);
+    }
+    if w1 & BID128_INF_MASK == BID128_INF_MASK {
+      return Value::Infinity(sign);
+    }
+    if w1 & BID128_STEERING_MASK == BID128_STEERING_MASK {
+      // coefficients in this form are always greater than the maximum, so they are non-canonical
+      let exponent = ((w1 >> 47) & 0x3fff) as i32 - BID128_BIAS;
+      return Value::Finite(sign, 0, exponent, Status::NONE);
+    }
+    let exponent = ((w1 >> 49) & 0x3fff) as i32 - BID128_BIAS;
+    let mut coefficient = ((w1 & BID128_COEFFICIENT_MASK) as u128) << 64 | w0 as u128;
+    if coefficient > MAX_COEFFICIENT {
+      coefficient = 0;
+    }
+    Value::Finite(sign, coefficient, exponent, Status::NONE)
+  }
+
+  /// Converts this decimal into text using the to-engineering-string rules,
+  /// the exponent (if present) is always a multiple of three.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (value, _) = bid128_from_string("7E-7");
+  /// assert_eq!("700E-9", value.to_engineering_string());
+  ///
+  /// let (value, _) = bid128_from_string("0E+1");
+  /// assert_eq!("0.00E+3", value.to_engineering_string());
+  /// ```
+  pub fn to_engineering_string(&self) -> String {
+    let mut s = String::new();
+    let _ = write_engineering(&mut s, &self.unpack());
+    s
+  }
 }
 
-/// Parses a 128-bit floating-point decimal from text in scientific notation, with rounding mode.
-pub fn bid128_from_string_rnd(input: &str, rnd: Rounding) -> (Bid128, u32) {
-  match recognize(input, BID128_NAX_DIGITS as usize, rnd) {
-    Value::Finite(sign, mut value, mut exponent, status) => {
-      let mut flags = status;
-      let e;
-      if value == 0 {
-        if exponent < -(BID128_BIAS + BID128_NAX_DIGITS) {
-          flags |= FLAG_UNDERFLOW | FLAG_INEXACT;
-          e = 0;
-        } else if exponent < -BID128_BIAS {
-          e = 0;
-        } else if exponent < MAX_EXPONENT {
-          e = (BID128_BIAS + exponent) as u64;
+/// Classes of floating-point decimals defined by IEEE 754.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Class {
+  /// Signaling NaN.
+  SignalingNaN,
+  /// Quiet NaN.
+  QuietNaN,
+  /// Negative infinity.
+  NegativeInfinity,
+  /// Negative normal number.
+  NegativeNormal,
+  /// Negative subnormal number.
+  NegativeSubnormal,
+  /// Negative zero.
+  NegativeZero,
+  /// Positive zero.
+  PositiveZero,
+  /// Positive subnormal number.
+  PositiveSubnormal,
+  /// Positive normal number.
+  PositiveNormal,
+  /// Positive infinity.
+  PositiveInfinity,
+}
+
+impl Bid128 {
+  /// Creates a finite decimal from the sign, coefficient and exponent, without rounding.
+  ///
+  /// Coefficients below `2^113` are encoded in the first form, coefficients in range
+  /// `2^113..2^113 + 2^111` are encoded in the second form, with the implicit `100` bits.
+  /// Coefficients greater than 34 digits are encoded as given and are not canonical.
+  ///
+  /// Returns `None` when the coefficient or the exponent can not be encoded.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::Bid128;
+  ///
+  /// let value = Bid128::from_parts(true, 12345, -2).unwrap();
+  /// assert_eq!("-123.45", value.to_string());
+  /// assert!(Bid128::from_parts(false, 1, 6112).is_none());
+  /// ```
+  pub fn from_parts(sign: bool, coefficient: u128, exponent: i32) -> Option<Bid128> {
+    let biased = exponent
+      .checked_add(BID128_BIAS)
+      .filter(|e| (0..=MAX_BIASED_EXPONENT as i32).contains(e))? as u64;
+    let s = if sign { BID128_SIGN } else { 0 };
+    if coefficient < BID128_LARGE_COEFFICIENT {
+      Some(Bid128 {
+        w: [coefficient as u64, ((coefficient >> 64) as u64) | biased << 49 | s],
+      })
+    } else if coefficient - BID128_LARGE_COEFFICIENT
+      <= ((BID128_LARGE_COEFFICIENT_MASK as u128) << 64 | u64::MAX as u128)
+    {
+      Some(Bid128 {
+        w: [
+          coefficient as u64,
+          ((coefficient >> 64) as u64 & BID128_LARGE_COEFFICIENT_MASK) | biased << 47 | BID128_STEERING_MASK | s,
+        ],
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Returns `true` when the sign bit is set, also for zeros, infinities and NaNs.
+  pub fn is_sign_negative(&self) -> bool {
+    self.w[1] & BID128_SIGN != 0
+  }
+
+  /// Returns the coefficient of a finite decimal or the payload of a NaN,
+  /// non-canonical coefficients and payloads are returned as zero, infinities have zero coefficient.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (value, _) = bid128_from_string("-1.250");
+  /// assert_eq!(1250, value.coefficient());
+  /// assert_eq!(-3, value.exponent());
+  /// ```
+  pub fn coefficient(&self) -> u128 {
+    match self.unpack() {
+      Value::Finite(_, coefficient, _, _) => coefficient,
+      Value::NaN(_, _, payload) => payload,
+      _ => 0,
+    }
+  }
+
+  /// Returns the unbiased exponent of a finite decimal, infinities and NaNs have zero exponent.
+  pub fn exponent(&self) -> i32 {
+    match self.unpack() {
+      Value::Finite(_, _, exponent, _) => exponent,
+      _ => 0,
+    }
+  }
+
+  /// Returns `true` when this decimal is a quiet or signaling NaN.
+  pub fn is_nan(&self) -> bool {
+    self.w[1] & BID128_NAN_MASK == BID128_NAN_MASK
+  }
+
+  /// Returns `true` when this decimal is a signaling NaN.
+  pub fn is_snan(&self) -> bool {
+    self.w[1] & (BID128_NAN_MASK | BID128_SNAN_MASK) == BID128_NAN_MASK | BID128_SNAN_MASK
+  }
+
+  /// Returns `true` when this decimal is a positive or negative infinity.
+  pub fn is_infinite(&self) -> bool {
+    self.w[1] & BID128_NAN_MASK == BID128_INF_MASK
+  }
+
+  /// Returns `true` when this decimal is neither an infinity nor a NaN.
+  pub fn is_finite(&self) -> bool {
+    self.w[1] & BID128_INF_MASK != BID128_INF_MASK
+  }
+
+  /// Returns `true` when this decimal is a positive or negative zero, including non-canonical coefficients.
+  pub fn is_zero(&self) -> bool {
+    matches!(self.unpack(), Value::Finite(_, 0, _, _))
+  }
+
+  /// Returns `true` when this decimal is encoded in the canonical form.
+  ///
+  /// Non-canonical encodings have coefficients or payloads out of range,
+  /// or nonzero bits ignored in infinities and NaNs.
+  pub fn is_canonical(&self) -> bool {
+    let [w0, w1] = self.w;
+    if self.is_nan() {
+      let payload = ((w1 & BID128_PAYLOAD_MASK) as u128) << 64 | w0 as u128;
+      w1 & !(BID128_SIGN | BID128_NAN_MASK | BID128_SNAN_MASK | BID128_PAYLOAD_MASK) == 0 && payload <= MAX_PAYLOAD
+    } else if self.is_infinite() {
+      w1 & !(BID128_SIGN | BID128_INF_MASK) == 0 && w0 == 0
+    } else if w1 & BID128_STEERING_MASK == BID128_STEERING_MASK {
+      false
+    } else {
+      ((w1 & BID128_COEFFICIENT_MASK) as u128) << 64 | w0 as u128 <= MAX_COEFFICIENT
+    }
+  }
+
+  /// Returns the class of this decimal.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Class};
+  ///
+  /// let (value, _) = bid128_from_string("-0");
+  /// assert_eq!(Class::NegativeZero, value.class());
+  ///
+  /// let (value, _) = bid128_from_string("1e-6176");
+  /// assert_eq!(Class::PositiveSubnormal, value.class());
+  /// ```
+  pub fn class(&self) -> Class {
+    match self.unpack() {
+      Value::NaN(_, true, _) => Class::SignalingNaN,
+      Value::NaN(_, false, _) => Class::QuietNaN,
+      Value::Infinity(true) => Class::NegativeInfinity,
+      Value::Infinity(false) => Class::PositiveInfinity,
+      Value::Finite(sign, 0, _, _) => {
+        if sign {
+          Class::NegativeZero
         } else {
-          e = (BID128_BIAS + MAX_EXPONENT) as u64;
-        }
-      } else {
-        if exponent > MAX_EXPONENT {
-          // try to normalize before reporting an overflow
-          while exponent > MAX_EXPONENT {
-            let n = value * 10;
-            if n <= MAX_COEFFICIENT {
-              value = n;
-              exponent -= 1;
-            } else {
-              break;
-            }
-          }
-          if exponent > MAX_EXPONENT {
-            // +inf, overflow, inexact
-            flags |= FLAG_OVERFLOW | FLAG_INEXACT;
-            return if sign {
-              (BID128_NEG_INF, flags)
-            } else {
-              (BID128_INF, flags)
-            };
-          }
+          Class::PositiveZero
         }
-        if exponent < -BID128_BIAS {
-          // try to normalize before reporting an underflow
-          while exponent < -BID128_BIAS {
-            let r = value % 10;
-            if r == 0 {
-              value /= 10;
-              exponent += 1;
-            } else {
-              break;
-            }
-          }
-          if exponent < -BID128_BIAS {
-            //  underflow, inexact
-            flags |= FLAG_UNDERFLOW | FLAG_INEXACT;
-            return if sign {
-              (BID128_NEG_MIN, flags)
-            } else {
-              (BID128_MIN, flags)
-            };
-          }
+      }
+      Value::Finite(sign, coefficient, exponent, _) => {
+        // subnormal numbers have the adjusted exponent less than the minimum exponent
+        let subnormal = exponent + (coefficient.ilog10() as i32) < 1 - BID128_EMAX;
+        match (sign, subnormal) {
+          (true, true) => Class::NegativeSubnormal,
+          (true, false) => Class::NegativeNormal,
+          (false, true) => Class::PositiveSubnormal,
+          (false, false) => Class::PositiveNormal,
         }
-        e = (BID128_BIAS + exponent) as u64;
       }
-      let s = if sign { BID128_SIGN } else { 0 };
-      (
-        Bid128 {
-          w: [value as u64, ((value >> 64) as u64) | e << 49 | s],
-        },
-        flags,
-      )
+      Value::Invalid(..) => Class::QuietNaN,
     }
-    Value::Infinity(sign) => {
-      if sign {
-        (BID128_NEG_INF, 0)
-      } else {
-        (BID128_INF, 0)
-      }
+  }
+}
+
+impl Bid128 {
+  /// Parses a 128-bit floating-point decimal from text in scientific notation,
+  /// reports syntax errors and exceptional results rejected by the policy.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{Bid128, FromStrError, RangePolicy};
+  ///
+  /// let value = Bid128::from_str_with_policy("1e6145", RangePolicy::ACCEPT_ALL).unwrap();
+  /// assert_eq!("Infinity", value.to_string());
+  ///
+  /// let result = Bid128::from_str_with_policy("1e6145", RangePolicy::default());
+  /// assert!(matches!(result, Err(FromStrError::Overflow)));
+  /// ```
+  pub fn from_str_with_policy(input: &str, policy: RangePolicy) -> Result<Bid128, FromStrError> {
+    let (value, status) = try_bid128_from_string(input)?;
+    policy.check(status)?;
+    Ok(value)
+  }
+}
+
+impl FromStr for Bid128 {
+  type Err = FromStrError;
+
+  /// Parses [Bid128] from text using the default [RangePolicy].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::Bid128;
+  ///
+  /// let value = "1.5e3".parse::<Bid128>().unwrap();
+  /// assert_eq!("1.5E+3", value.to_string());
+  /// assert!("1.5e".parse::<Bid128>().is_err());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Bid128::from_str_with_policy(s, RangePolicy::default())
+  }
+}
+
+impl TryFrom<&str> for Bid128 {
+  type Error = FromStrError;
+
+  /// Converts text into [Bid128] using the default [RangePolicy].
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl fmt::Display for Bid128 {
+  /// Formats the decimal using the to-scientific-string rules, padded to the requested width.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pad_scientific(f, &self.unpack())
+  }
+}
+
+/// Parses a 128-bit floating-point decimal from text in scientific notation.
+pub fn bid128_from_string(input: &str) -> (Bid128, Status) {
+  bid128_from_string_rnd(input, Rounding::ToNearest)
+}
+
+/// Parses a 128-bit floating-point decimal from text in scientific notation, with rounding mode.
+pub fn bid128_from_string_rnd(input: &str, rnd: Rounding) -> (Bid128, Status) {
+  bid128_from_bytes_rnd(input.as_bytes(), rnd)
+}
+
+/// Parses a 128-bit floating-point decimal from ASCII text in scientific notation.
+///
+/// The input is not required to be valid UTF-8, the result is the same
+/// as for the text parsed by [bid128_from_string].
+///
+/// # Examples
+///
+/// ```
+/// use scidec::bid128_from_bytes;
+///
+/// let (value, status) = bid128_from_bytes(b"-12.50e3");
+/// assert_eq!("-1.250E+4", value.to_string());
+/// assert!(status.is_empty());
+///
+/// let (value, status) = bid128_from_bytes(b"1\xff");
+/// assert_eq!("NaN", value.to_string());
+/// assert!(status.is_invalid());
+/// ```
+pub fn bid128_from_bytes(input: &[u8]) -> (Bid128, Status) {
+  bid128_from_bytes_rnd(input, Rounding::ToNearest)
+}
+
+/// Parses a 128-bit floating-point decimal from ASCII text in scientific notation, with rounding mode.
+pub fn bid128_from_bytes_rnd(input: &[u8], rnd: Rounding) -> (Bid128, Status) {
+  bid128_from_value(
+    recognize(
+      input,
+      BID128_NAX_DIGITS as usize,
+      rnd,
+      Mode::Lenient,
+      &ParseOptions::new(),
+    )
+    .0,
+  )
+}
+
+/// Parses a 128-bit floating-point decimal from text in scientific notation,
+/// reports an error when the text is not a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{try_bid128_from_string, ParseErrorKind};
+///
+/// let (value, status) = try_bid128_from_string("1234.5678e-2").unwrap();
+/// assert_eq!("12.345678", value.to_string());
+/// assert!(status.is_empty());
+///
+/// let err = try_bid128_from_string("12x").err().unwrap();
+/// assert_eq!(ParseErrorKind::UnexpectedCharacter, err.kind());
+/// assert_eq!(2, err.offset());
+///
+/// let err = try_bid128_from_string("1e").err().unwrap();
+/// assert_eq!(ParseErrorKind::MissingExponentDigits, err.kind());
+/// ```
+pub fn try_bid128_from_string(input: &str) -> Result<(Bid128, Status), ParseError> {
+  try_bid128_from_string_rnd(input, Rounding::ToNearest)
+}
+
+/// Parses a 128-bit floating-point decimal from text in scientific notation, with rounding mode,
+/// reports an error when the text is not a valid number.
+pub fn try_bid128_from_string_rnd(input: &str, rnd: Rounding) -> Result<(Bid128, Status), ParseError> {
+  match recognize(
+    input.as_bytes(),
+    BID128_NAX_DIGITS as usize,
+    rnd,
+    Mode::Strict,
+    &ParseOptions::new(),
+  )
+  .0
+  {
+    Value::Invalid(_, err) => Err(err),
+    value => Ok(bid128_from_value(value)),
+  }
+}
+
+/// Parses a 128-bit floating-point decimal from text in scientific notation, with rounding mode
+/// and parsing options, reports an error when the text is not a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{bid128_from_string_with_options, ParseOptions, Rounding};
+///
+/// let options = ParseOptions::new().with_group_separator('_');
+/// let (value, _) = bid128_from_string_with_options("1_000_000.000_1", Rounding::ToNearest, &options).unwrap();
+/// assert_eq!("1000000.0001", value.to_string());
+///
+/// let options = ParseOptions::new().with_ignore_trailing(true);
+/// let (value, _) = bid128_from_string_with_options("25 kg", Rounding::ToNearest, &options).unwrap();
+/// assert_eq!("25", value.to_string());
+/// ```
+pub fn bid128_from_string_with_options(
+  input: &str,
+  rnd: Rounding,
+  options: &ParseOptions,
+) -> Result<(Bid128, Status), ParseError> {
+  match recognize(
+    input.as_bytes(),
+    BID128_NAX_DIGITS as usize,
+    rnd,
+    options.mode(),
+    options,
+  )
+  .0
+  {
+    Value::Invalid(_, err) => Err(err),
+    value => Ok(bid128_from_value(value)),
+  }
+}
+
+/// Parses a 128-bit floating-point decimal from the longest prefix of the text being a number
+/// in scientific notation, returns the number of bytes consumed from the text.
+///
+/// Reports an error when the text does not start with a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::bid128_from_string_prefix;
+///
+/// let (value, _, consumed) = bid128_from_string_prefix("12.5e3,-7").unwrap();
+/// assert_eq!("1.25E+4", value.to_string());
+/// assert_eq!(6, consumed);
+///
+/// let (value, _, consumed) = bid128_from_string_prefix("1e+x").unwrap();
+/// assert_eq!("1", value.to_string());
+/// assert_eq!(1, consumed);
+///
+/// assert!(bid128_from_string_prefix("x1").is_err());
+/// ```
+pub fn bid128_from_string_prefix(input: &str) -> Result<(Bid128, Status, usize), ParseError> {
+  bid128_from_string_prefix_rnd(input, Rounding::ToNearest)
+}
+
+/// Parses a 128-bit floating-point decimal from the longest prefix of the text being a number
+/// in scientific notation, with rounding mode, returns the number of bytes consumed from the text.
+///
+/// Reports an error when the text does not start with a valid number.
+pub fn bid128_from_string_prefix_rnd(input: &str, rnd: Rounding) -> Result<(Bid128, Status, usize), ParseError> {
+  match recognize(
+    input.as_bytes(),
+    BID128_NAX_DIGITS as usize,
+    rnd,
+    Mode::Prefix,
+    &ParseOptions::new(),
+  ) {
+    (Value::Invalid(_, err), _) => Err(err),
+    (value, consumed) => {
+      let (bid, status) = bid128_from_value(value);
+      Ok((bid, status, consumed))
     }
-    Value::NaN(sign, signaling) => match (sign, signaling) {
-      (false, false) => (BID128_NAN, 0),
-      (false, true) => (BID128_SNAN, 0),
-      (true, false) => (BID128_NEG_NAN, 0),
-      (true, true) => (BID128_NEG_SNAN, 0),
-    },
   }
 }
+
+/// Encodes the recognized value as a 128-bit floating-point decimal.
+pub(crate) fn bid128_from_value(value: Value) -> (Bid128, Status) {
+  let (w, status) = bid_from_value(value, &BID128);
+  (
+    Bid128 {
+      w: [w as u64, (w >> 64) as u64],
+    },
+    status,
+  )
+}
+
+/// Converts a 128-bit floating-point decimal into text in scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{bid128_from_string, bid128_to_string};
+///
+/// let (value, _) = bid128_from_string("1234.5678e-2");
+/// assert_eq!("12.345678", bid128_to_string(&value));
+///
+/// let (value, _) = bid128_from_string("-123e20");
+/// assert_eq!("-1.23E+22", bid128_to_string(&value));
+///
+/// let (value, _) = bid128_from_string("-inf");
+/// assert_eq!("-Infinity", bid128_to_string(&value));
+/// ```
+pub fn bid128_to_string(value: &Bid128) -> String {
+  value.to_string()
+}
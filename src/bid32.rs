@@ -0,0 +1,54 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Recognizer for 32-bit floating-point decimals.
+
+use crate::bid::{bid_from_value, BID32};
+use crate::options::ParseOptions;
+use crate::recognizer::{recognize, Mode};
+use crate::{Rounding, Status};
+
+/// 32-bit decimal in binary format.
+#[derive(Copy, Clone)]
+pub struct Bid32 {
+  pub w: u32,
+}
+
+/// Parses a 32-bit floating-point decimal from text in scientific notation.
+pub fn bid32_from_string(input: &str) -> (Bid32, Status) {
+  bid32_from_string_rnd(input, Rounding::ToNearest)
+}
+
+/// Parses a 32-bit floating-point decimal from text in scientific notation, with rounding mode.
+pub fn bid32_from_string_rnd(input: &str, rnd: Rounding) -> (Bid32, Status) {
+  let (value, _) = recognize(
+    input.as_bytes(),
+    BID32.digits as usize,
+    rnd,
+    Mode::Lenient,
+    &ParseOptions::new(),
+  );
+  let (w, status) = bid_from_value(value, &BID32);
+  (Bid32 { w: w as u32 }, status)
+}
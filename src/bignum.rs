@@ -0,0 +1,530 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Arbitrary precision unsigned integers used in exact conversions
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Largest power of ten fitting in a limb.
+const POW10_LIMB: u32 = 1_000_000_000;
+
+/// Number of decimal digits in [POW10_LIMB].
+const POW10_LIMB_DIGITS: u32 = 9;
+
+/// Largest power of five fitting in a limb.
+const POW5_LIMB: u32 = 1_220_703_125;
+
+/// Number of fives in [POW5_LIMB].
+const POW5_LIMB_DIGITS: u32 = 13;
+
+/// Unsigned integer stored as 32-bit limbs, the least significant limb first.
+///
+/// Limbs are kept normalized, the most significant limb is never zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigUint {
+  limbs: Vec<u32>,
+}
+
+impl BigUint {
+  /// Creates an integer from [u128].
+  pub fn from_u128(mut value: u128) -> Self {
+    let mut limbs = Vec::with_capacity(4);
+    while value > 0 {
+      limbs.push(value as u32);
+      value >>= 32;
+    }
+    Self { limbs }
+  }
+
+  /// Creates an integer from decimal digits, the most significant digit first.
+  pub fn from_digits(digits: &[u8]) -> Self {
+    let mut value = Self { limbs: Vec::new() };
+    for chunk in digits.chunks(POW10_LIMB_DIGITS as usize) {
+      value.mul_small(10_u32.pow(chunk.len() as u32));
+      value.add_small(chunk.iter().fold(0, |acc, digit| acc * 10 + *digit as u32));
+    }
+    value
+  }
+
+  /// Creates the integer `10^n`.
+  pub fn pow10(n: u32) -> Self {
+    let mut value = Self::from_u128(1);
+    value.mul_pow10(n);
+    value
+  }
+
+  /// Returns `true` when this integer is zero.
+  pub fn is_zero(&self) -> bool {
+    self.limbs.is_empty()
+  }
+
+  /// Returns the number of significant bits.
+  pub fn bit_len(&self) -> usize {
+    match self.limbs.last() {
+      Some(top) => self.limbs.len() * 32 - top.leading_zeros() as usize,
+      None => 0,
+    }
+  }
+
+  /// Returns the lowest 64 bits of this integer.
+  pub fn low_u64(&self) -> u64 {
+    let lo = self.limbs.first().copied().unwrap_or(0) as u64;
+    let hi = self.limbs.get(1).copied().unwrap_or(0) as u64;
+    hi << 32 | lo
+  }
+
+  /// Returns the lowest 128 bits of this integer.
+  pub fn low_u128(&self) -> u128 {
+    self
+      .limbs
+      .iter()
+      .take(4)
+      .rev()
+      .fold(0, |acc, limb| acc << 32 | *limb as u128)
+  }
+
+  /// Returns the number of decimal digits, zero has one digit.
+  pub fn decimal_digits(&self) -> usize {
+    if self.limbs.len() <= 4 {
+      return self.low_u128().checked_ilog10().unwrap_or(0) as usize + 1;
+    }
+    // 1233 / 4096 is slightly less than log10(2), so the estimate never exceeds the number of digits
+    let mut digits = (((self.bit_len() - 1) * 1233) >> 12) + 1;
+    let mut power = Self::pow10(digits as u32);
+    while *self >= power {
+      power.mul_small(10);
+      digits += 1;
+    }
+    digits
+  }
+
+  /// Multiplies this integer by a limb.
+  pub fn mul_small(&mut self, factor: u32) {
+    let mut carry = 0_u64;
+    for limb in self.limbs.iter_mut() {
+      let product = *limb as u64 * factor as u64 + carry;
+      *limb = product as u32;
+      carry = product >> 32;
+    }
+    if carry > 0 {
+      self.limbs.push(carry as u32);
+    }
+    self.normalize();
+  }
+
+  /// Adds a limb to this integer.
+  pub fn add_small(&mut self, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in self.limbs.iter_mut() {
+      if carry == 0 {
+        break;
+      }
+      let sum = *limb as u64 + carry;
+      *limb = sum as u32;
+      carry = sum >> 32;
+    }
+    if carry > 0 {
+      self.limbs.push(carry as u32);
+    }
+  }
+
+  /// Adds another integer to this integer.
+  pub fn add(&mut self, addend: &BigUint) {
+    if self.limbs.len() < addend.limbs.len() {
+      self.limbs.resize(addend.limbs.len(), 0);
+    }
+    let mut carry = 0_u64;
+    for (i, limb) in self.limbs.iter_mut().enumerate() {
+      let sum = *limb as u64 + addend.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+      *limb = sum as u32;
+      carry = sum >> 32;
+    }
+    if carry > 0 {
+      self.limbs.push(carry as u32);
+    }
+  }
+
+  /// Subtracts another integer from this integer, the subtrahend must not be greater than this integer.
+  pub fn sub(&mut self, subtrahend: &BigUint) {
+    assert!(*self >= *subtrahend, "subtraction overflow");
+    let mut borrow = 0_i64;
+    for (i, limb) in self.limbs.iter_mut().enumerate() {
+      let difference = *limb as i64 - subtrahend.limbs.get(i).copied().unwrap_or(0) as i64 - borrow;
+      *limb = difference as u32;
+      borrow = (difference < 0) as i64;
+    }
+    self.normalize();
+  }
+
+  /// Multiplies this integer by another integer.
+  pub fn mul(&self, factor: &BigUint) -> BigUint {
+    let mut limbs = vec![0_u32; self.limbs.len() + factor.limbs.len()];
+    for (i, a) in self.limbs.iter().enumerate() {
+      let mut carry = 0_u64;
+      for (j, b) in factor.limbs.iter().enumerate() {
+        let product = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+        limbs[i + j] = product as u32;
+        carry = product >> 32;
+      }
+      limbs[i + factor.limbs.len()] = carry as u32;
+    }
+    let mut product = BigUint { limbs };
+    product.normalize();
+    product
+  }
+
+  /// Multiplies this integer by `10^n`.
+  pub fn mul_pow10(&mut self, mut n: u32) {
+    while n >= POW10_LIMB_DIGITS {
+      self.mul_small(POW10_LIMB);
+      n -= POW10_LIMB_DIGITS;
+    }
+    self.mul_small(10_u32.pow(n));
+  }
+
+  /// Multiplies this integer by `5^n`.
+  pub fn mul_pow5(&mut self, mut n: u32) {
+    while n >= POW5_LIMB_DIGITS {
+      self.mul_small(POW5_LIMB);
+      n -= POW5_LIMB_DIGITS;
+    }
+    self.mul_small(5_u32.pow(n));
+  }
+
+  /// Shifts this integer left by the specified number of bits.
+  pub fn shl(&mut self, bits: usize) {
+    if self.is_zero() {
+      return;
+    }
+    let (limbs, bits) = (bits / 32, bits % 32);
+    if bits > 0 {
+      let mut carry = 0_u32;
+      for limb in self.limbs.iter_mut() {
+        let shifted = (*limb as u64) << bits | carry as u64;
+        *limb = shifted as u32;
+        carry = (shifted >> 32) as u32;
+      }
+      if carry > 0 {
+        self.limbs.push(carry);
+      }
+    }
+    self.limbs.splice(0..0, core::iter::repeat_n(0, limbs));
+  }
+
+  /// Divides this integer by a limb, returns the remainder.
+  pub fn div_rem_small(&mut self, divisor: u32) -> u32 {
+    let mut remainder = 0_u64;
+    for limb in self.limbs.iter_mut().rev() {
+      let dividend = remainder << 32 | *limb as u64;
+      *limb = (dividend / divisor as u64) as u32;
+      remainder = dividend % divisor as u64;
+    }
+    self.normalize();
+    remainder as u32
+  }
+
+  /// Divides this integer by another integer, returns the quotient and the remainder.
+  ///
+  /// Implements the algorithm D from Knuth's "The Art of Computer Programming", Vol. 2, 4.3.1.
+  pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+    assert!(!divisor.is_zero(), "division by zero");
+    if self < divisor {
+      return (BigUint::from_u128(0), self.clone());
+    }
+    if divisor.limbs.len() == 1 {
+      let mut quotient = self.clone();
+      let remainder = quotient.div_rem_small(divisor.limbs[0]);
+      return (quotient, BigUint::from_u128(remainder as u128));
+    }
+    // normalize, so the most significant bit of the divisor is set
+    let shift = divisor.limbs[divisor.limbs.len() - 1].leading_zeros() as usize;
+    let mut v = divisor.clone();
+    v.shl(shift);
+    let mut u = self.clone();
+    u.shl(shift);
+    u.limbs.resize(self.limbs.len() + 1, 0);
+    let v = v.limbs;
+    let n = v.len();
+    let m = u.limbs.len() - n;
+    let mut quotient = vec![0_u32; m];
+    let b = 1_u64 << 32;
+    for j in (0..m).rev() {
+      // estimate the quotient digit
+      let dividend = (u.limbs[j + n] as u64) << 32 | u.limbs[j + n - 1] as u64;
+      let mut q = dividend / v[n - 1] as u64;
+      let mut r = dividend % v[n - 1] as u64;
+      while q >= b || q * v[n - 2] as u64 > (r << 32 | u.limbs[j + n - 2] as u64) {
+        q -= 1;
+        r += v[n - 1] as u64;
+        if r >= b {
+          break;
+        }
+      }
+      // multiply and subtract
+      let mut borrow = 0_i64;
+      let mut carry = 0_u64;
+      for (i, &vi) in v.iter().enumerate() {
+        let product = q * vi as u64 + carry;
+        carry = product >> 32;
+        let t = u.limbs[i + j] as i64 - borrow - (product & 0xffffffff) as i64;
+        u.limbs[i + j] = t as u32;
+        borrow = (t < 0) as i64;
+      }
+      let t = u.limbs[j + n] as i64 - borrow - carry as i64;
+      u.limbs[j + n] = t as u32;
+      // add back when the estimated quotient digit was one too large
+      if t < 0 {
+        q -= 1;
+        let mut carry = 0_u64;
+        for (i, &vi) in v.iter().enumerate() {
+          let sum = u.limbs[i + j] as u64 + vi as u64 + carry;
+          u.limbs[i + j] = sum as u32;
+          carry = sum >> 32;
+        }
+        u.limbs[j + n] = u.limbs[j + n].wrapping_add(carry as u32);
+      }
+      quotient[j] = q as u32;
+    }
+    // denormalize the remainder
+    u.limbs.truncate(n);
+    let mut remainder = BigUint { limbs: u.limbs };
+    remainder.normalize();
+    remainder.shr(shift);
+    let mut quotient = BigUint { limbs: quotient };
+    quotient.normalize();
+    (quotient, remainder)
+  }
+
+  /// Returns the square root of this integer, rounded down.
+  ///
+  /// Newton's iteration starts from a power of two not less than the square root.
+  pub fn isqrt(&self) -> BigUint {
+    if self.is_zero() {
+      return self.clone();
+    }
+    let mut root = BigUint::from_u128(1);
+    root.shl(self.bit_len().div_ceil(2));
+    loop {
+      let (mut next, _) = self.div_rem(&root);
+      next.add(&root);
+      next.div_rem_small(2);
+      if next >= root {
+        return root;
+      }
+      root = next;
+    }
+  }
+
+  /// Returns decimal digits of this integer.
+  #[cfg(test)]
+  pub fn to_decimal_string(&self) -> alloc::string::String {
+    let mut chunks = vec![];
+    let mut value = self.clone();
+    while !value.is_zero() {
+      chunks.push(value.div_rem_small(POW10_LIMB));
+    }
+    let mut digits = alloc::string::String::new();
+    match chunks.pop() {
+      Some(top) => digits.push_str(&alloc::format!("{top}")),
+      None => digits.push('0'),
+    }
+    for chunk in chunks.iter().rev() {
+      digits.push_str(&alloc::format!("{chunk:09}"));
+    }
+    digits
+  }
+
+  /// Shifts this integer right by less than 32 bits.
+  fn shr(&mut self, bits: usize) {
+    if bits > 0 {
+      let mut carry = 0_u32;
+      for limb in self.limbs.iter_mut().rev() {
+        let shifted = *limb;
+        *limb = shifted >> bits | carry;
+        carry = shifted << (32 - bits);
+      }
+      self.normalize();
+    }
+  }
+
+  /// Removes the most significant zero limbs.
+  fn normalize(&mut self) {
+    while self.limbs.last() == Some(&0) {
+      self.limbs.pop();
+    }
+  }
+}
+
+impl PartialOrd for BigUint {
+  /// Compares two integers.
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for BigUint {
+  /// Compares two integers.
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .limbs
+      .len()
+      .cmp(&other.limbs.len())
+      .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decimal_string() {
+    assert_eq!("0", BigUint::from_u128(0).to_decimal_string());
+    assert_eq!("1000000000", BigUint::from_u128(1000000000).to_decimal_string());
+    assert_eq!(
+      "340282366920938463463374607431768211455",
+      BigUint::from_u128(u128::MAX).to_decimal_string()
+    );
+    let mut value = BigUint::from_u128(7);
+    value.mul_pow10(40);
+    assert_eq!("70000000000000000000000000000000000000000", value.to_decimal_string());
+    let mut value = BigUint::from_u128(1);
+    value.mul_pow5(30);
+    assert_eq!("931322574615478515625", value.to_decimal_string());
+  }
+
+  #[test]
+  fn test_from_digits() {
+    assert!(BigUint::from_digits(&[]).is_zero());
+    assert!(BigUint::from_digits(&[0, 0]).is_zero());
+    assert_eq!(
+      BigUint::from_u128(1234567890123),
+      BigUint::from_digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3])
+    );
+    let digits = [9; 38];
+    assert_eq!(BigUint::from_u128(10_u128.pow(38) - 1), BigUint::from_digits(&digits));
+  }
+
+  #[test]
+  fn test_add_sub_mul() {
+    let mut value = BigUint::from_u128(u128::MAX);
+    value.add(&BigUint::from_u128(1));
+    assert_eq!(129, value.bit_len());
+    value.sub(&BigUint::from_u128(2));
+    assert_eq!(BigUint::from_u128(u128::MAX - 1), value);
+    let mut value = BigUint::from_u128(5);
+    value.add(&BigUint::from_u128(1 << 100));
+    assert_eq!(BigUint::from_u128((1 << 100) + 5), value);
+    value.sub(&BigUint::from_u128((1 << 100) + 5));
+    assert!(value.is_zero());
+    let product = BigUint::from_u128(u64::MAX as u128).mul(&BigUint::from_u128(u64::MAX as u128));
+    assert_eq!(BigUint::from_u128(u64::MAX as u128 * u64::MAX as u128), product);
+    let product = BigUint::from_u128(10_u128.pow(34) - 1).mul(&BigUint::from_u128(10_u128.pow(34) - 1));
+    assert_eq!(
+      "99999999999999999999999999999999980000000000000000000000000000000001",
+      product.to_decimal_string()
+    );
+    assert!(BigUint::from_u128(0).mul(&BigUint::from_u128(7)).is_zero());
+  }
+
+  #[test]
+  fn test_decimal_digits() {
+    assert_eq!(1, BigUint::from_u128(0).decimal_digits());
+    assert_eq!(1, BigUint::from_u128(9).decimal_digits());
+    assert_eq!(2, BigUint::from_u128(10).decimal_digits());
+    assert_eq!(39, BigUint::from_u128(u128::MAX).decimal_digits());
+    assert_eq!(u128::MAX, BigUint::from_u128(u128::MAX).low_u128());
+    for n in [38, 39, 40, 68, 100, 1000, 12345] {
+      let mut value = BigUint::pow10(n);
+      assert_eq!(n as usize + 1, value.decimal_digits());
+      value.sub(&BigUint::from_u128(1));
+      assert_eq!(n as usize, value.decimal_digits());
+      assert_eq!(value.to_decimal_string().len(), value.decimal_digits());
+    }
+  }
+
+  #[test]
+  fn test_isqrt() {
+    assert!(BigUint::from_u128(0).isqrt().is_zero());
+    assert_eq!(BigUint::from_u128(1), BigUint::from_u128(1).isqrt());
+    assert_eq!(BigUint::from_u128(1), BigUint::from_u128(3).isqrt());
+    assert_eq!(BigUint::from_u128(2), BigUint::from_u128(4).isqrt());
+    assert_eq!(
+      BigUint::from_u128(u64::MAX as u128),
+      BigUint::from_u128(u128::MAX).isqrt()
+    );
+    let mut value = BigUint::from_u128(2);
+    value.mul_pow10(68);
+    assert_eq!("14142135623730950488016887242096980", value.isqrt().to_decimal_string());
+  }
+
+  #[test]
+  fn test_shl() {
+    let mut value = BigUint::from_u128(3);
+    value.shl(100);
+    assert_eq!(102, value.bit_len());
+    assert_eq!(BigUint::from_u128(3 << 100), value);
+  }
+
+  #[test]
+  fn test_div_rem() {
+    let (q, r) = BigUint::from_u128(u128::MAX).div_rem(&BigUint::from_u128(10_u128.pow(20) + 7));
+    assert_eq!(BigUint::from_u128(u128::MAX / (10_u128.pow(20) + 7)), q);
+    assert_eq!(BigUint::from_u128(u128::MAX % (10_u128.pow(20) + 7)), r);
+    let (q, r) = BigUint::from_u128(12345).div_rem(&BigUint::from_u128(10_u128.pow(30)));
+    assert!(q.is_zero());
+    assert_eq!(BigUint::from_u128(12345), r);
+    // (10^60 + 12345) / 10^40
+    let mut dividend = BigUint::from_u128(1);
+    dividend.mul_pow10(60);
+    let mut divisor = BigUint::from_u128(1);
+    divisor.mul_pow10(40);
+    let mut expected_q = BigUint::from_u128(1);
+    expected_q.mul_pow10(20);
+    let (q, r) = dividend.div_rem(&divisor);
+    assert_eq!(expected_q, q);
+    assert!(r.is_zero());
+    // values exercising the add back step
+    for (a, b) in [
+      (
+        0x0000_0001_0000_0000_0000_0000_0000_0000_u128,
+        0x0000_0000_8000_0000_0000_0001_u128,
+      ),
+      (
+        0x7fff_8000_0000_0000_0000_0000_0000_0000,
+        0x8000_0000_0000_0000_0000_0001,
+      ),
+      (
+        0xffff_ffff_ffff_ffff_0000_0000_0000_0003,
+        0x0000_0001_0000_0000_0000_0000_0000_0003,
+      ),
+    ] {
+      let (q, r) = BigUint::from_u128(a).div_rem(&BigUint::from_u128(b));
+      assert_eq!(BigUint::from_u128(a / b), q);
+      assert_eq!(BigUint::from_u128(a % b), r);
+    }
+  }
+}
@@ -0,0 +1,495 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Conversions between decimal and binary floating-point numbers
+
+use crate::arithmetic::round_result;
+use crate::bid128::{bid128_from_value, Bid128};
+use crate::bignum::BigUint;
+use crate::error::TryFromFloatError;
+use crate::options::ParseOptions;
+use crate::powers::{pow5_binary_exponent, POW5_128, POW5_MAX_EXACT_EXPONENT, POW5_MAX_EXPONENT, POW5_MIN_EXPONENT};
+use crate::recognizer::{scan, Digits, Mode, Rounding, Scanned, Value};
+use crate::Status;
+
+/// Parameters of a binary floating-point format.
+struct BinaryFormat {
+  /// Number of bits in the significand, including the implicit bit.
+  precision: i64,
+  /// Maximum unbiased exponent, equal to the exponent bias.
+  emax: i64,
+  /// Decimal exponent of numbers always overflowing this format.
+  huge: i32,
+  /// Decimal exponent of numbers always rounded like the half of the smallest subnormal.
+  tiny: i32,
+}
+
+impl BinaryFormat {
+  /// Minimum unbiased exponent of normal numbers.
+  fn emin(&self) -> i64 {
+    1 - self.emax
+  }
+}
+
+/// Parameters of binary64 format (f64).
+const BINARY64: BinaryFormat = BinaryFormat {
+  precision: 53,
+  emax: 1023,
+  huge: 309,
+  tiny: -326,
+};
+
+/// Parameters of binary32 format (f32).
+const BINARY32: BinaryFormat = BinaryFormat {
+  precision: 24,
+  emax: 127,
+  huge: 39,
+  tiny: -47,
+};
+
+impl Bid128 {
+  /// Converts this decimal into the nearest 64-bit binary floating-point number,
+  /// with rounding mode.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (value, _) = bid128_from_string("0.1");
+  /// let (down, status) = value.to_f64_rnd(Rounding::Down);
+  /// let (up, _) = value.to_f64_rnd(Rounding::Up);
+  /// assert_eq!(0.1, up);
+  /// assert_eq!(0.09999999999999999, down);
+  /// assert!(status.is_inexact());
+  /// ```
+  pub fn to_f64_rnd(&self, rnd: Rounding) -> (f64, Status) {
+    let (bits, status) = self.binary_bits(rnd, &BINARY64);
+    (f64::from_bits(bits), status)
+  }
+
+  /// Converts this decimal into the nearest 32-bit binary floating-point number,
+  /// with rounding mode.
+  pub fn to_f32_rnd(&self, rnd: Rounding) -> (f32, Status) {
+    let (bits, status) = self.binary_bits(rnd, &BINARY32);
+    (f32::from_bits(bits as u32), status)
+  }
+
+  /// Converts a 64-bit binary floating-point number into the nearest decimal,
+  /// with rounding mode.
+  ///
+  /// The result has the smallest exponent needed to represent the binary number exactly,
+  /// but not greater than zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{Bid128, Rounding};
+  ///
+  /// let (value, status) = Bid128::from_f64_rnd(2.5, Rounding::ToNearest);
+  /// assert_eq!("2.5", value.to_string());
+  /// assert!(status.is_empty());
+  ///
+  /// let (value, status) = Bid128::from_f64_rnd(0.1, Rounding::ToNearest);
+  /// assert_eq!("0.1000000000000000055511151231257827", value.to_string());
+  /// assert!(status.is_inexact());
+  /// ```
+  pub fn from_f64_rnd(value: f64, rnd: Rounding) -> (Bid128, Status) {
+    let bits = value.to_bits();
+    let sign = bits >> 63 == 1;
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0x000fffffffffffff;
+    match exponent {
+      0x7ff if fraction == 0 => bid128_from_value(Value::Infinity(sign)),
+      0x7ff => from_binary_nan(sign, fraction & 0x0008000000000000 == 0),
+      0 => from_binary(sign, fraction, -1074, rnd),
+      _ => from_binary(sign, fraction | 0x0010000000000000, exponent - 1075, rnd),
+    }
+  }
+
+  /// Converts a 32-bit binary floating-point number into the nearest decimal,
+  /// with rounding mode.
+  ///
+  /// The exponent of the result is chosen like in [Bid128::from_f64_rnd].
+  pub fn from_f32_rnd(value: f32, rnd: Rounding) -> (Bid128, Status) {
+    let bits = value.to_bits();
+    let sign = bits >> 31 == 1;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let fraction = (bits & 0x007fffff) as u64;
+    match exponent {
+      0xff if fraction == 0 => bid128_from_value(Value::Infinity(sign)),
+      0xff => from_binary_nan(sign, fraction & 0x00400000 == 0),
+      0 => from_binary(sign, fraction, -149, rnd),
+      _ => from_binary(sign, fraction | 0x00800000, exponent - 150, rnd),
+    }
+  }
+
+  /// Converts this decimal into the bits of the binary floating-point number in specified format.
+  fn binary_bits(&self, rnd: Rounding, format: &BinaryFormat) -> (u64, Status) {
+    let sign_bit = sign_mask(format);
+    match self.unpack() {
+      Value::Finite(sign, coefficient, exponent, _) => {
+        let sign_bit = if sign { sign_bit } else { 0 };
+        if coefficient == 0 {
+          return (sign_bit, Status::NONE);
+        }
+        let adjusted = exponent + coefficient.ilog10() as i32;
+        let (bits, status) = round_decimal(sign, BigUint::from_u128(coefficient), exponent, adjusted, rnd, format);
+        (sign_bit | bits, status)
+      }
+      Value::Infinity(sign) => {
+        let sign_bit = if sign { sign_bit } else { 0 };
+        (sign_bit | infinity(format), Status::NONE)
+      }
+      Value::NaN(sign, signaling, _) => {
+        // signaling NaN is converted into quiet NaN, the payload is not preserved
+        let sign_bit = if sign { sign_bit } else { 0 };
+        let status = if signaling { Status::INVALID } else { Status::NONE };
+        (sign_bit | quiet_nan(format), status)
+      }
+      Value::Invalid(sign, _) => {
+        let sign_bit = if sign { sign_bit } else { 0 };
+        (sign_bit | quiet_nan(format), Status::NONE)
+      }
+    }
+  }
+}
+
+impl TryFrom<f64> for Bid128 {
+  type Error = TryFromFloatError;
+
+  /// Converts [f64] into [Bid128], fails when the conversion is not exact.
+  fn try_from(value: f64) -> Result<Self, Self::Error> {
+    match Bid128::from_f64_rnd(value, Rounding::ToNearest) {
+      (bid, status) if status.is_empty() => Ok(bid),
+      (_, status) => Err(TryFromFloatError::new(status)),
+    }
+  }
+}
+
+impl TryFrom<f32> for Bid128 {
+  type Error = TryFromFloatError;
+
+  /// Converts [f32] into [Bid128], fails when the conversion is not exact.
+  fn try_from(value: f32) -> Result<Self, Self::Error> {
+    match Bid128::from_f32_rnd(value, Rounding::ToNearest) {
+      (bid, status) if status.is_empty() => Ok(bid),
+      (_, status) => Err(TryFromFloatError::new(status)),
+    }
+  }
+}
+
+/// Parses a 64-bit binary floating-point number from text in scientific notation, with rounding mode.
+///
+/// The result is correctly rounded, all significant digits of the text are taken into account.
+/// Text that is not a valid number is converted into NaN and reported as invalid.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{f64_from_string_rnd, Rounding};
+///
+/// let (value, status) = f64_from_string_rnd("0.1", Rounding::ToNearest);
+/// assert_eq!(0.1, value);
+/// assert!(status.is_inexact());
+///
+/// let (value, _) = f64_from_string_rnd("0.1", Rounding::Down);
+/// assert_eq!(0.09999999999999999, value);
+/// ```
+pub fn f64_from_string_rnd(input: &str, rnd: Rounding) -> (f64, Status) {
+  let (bits, status) = binary_from_string(input.as_bytes(), rnd, &BINARY64);
+  (f64::from_bits(bits), status)
+}
+
+/// Parses a 32-bit binary floating-point number from text in scientific notation, with rounding mode.
+///
+/// The result is rounded like in [f64_from_string_rnd].
+pub fn f32_from_string_rnd(input: &str, rnd: Rounding) -> (f32, Status) {
+  let (bits, status) = binary_from_string(input.as_bytes(), rnd, &BINARY32);
+  (f32::from_bits(bits as u32), status)
+}
+
+/// Maximum number of significant digits of numbers converted using the fast path.
+const FAST_PATH_DIGITS: usize = 19;
+
+/// Maximum number of significant digits stored when scanning binary floating-point numbers.
+///
+/// Deciding the correct rounding of a binary64 value may require up to 768 significant decimal digits,
+/// the remaining digits only affect the sticky bit.
+const MAX_BINARY_DIGITS: usize = 800;
+
+/// Parses the bits of the binary floating-point number in specified format.
+fn binary_from_string(input: &[u8], rnd: Rounding, format: &BinaryFormat) -> (u64, Status) {
+  let sign_bit = sign_mask(format);
+  match scan::<MAX_BINARY_DIGITS>(input, FAST_PATH_DIGITS, Mode::Lenient, &ParseOptions::new()).0 {
+    Scanned::Finite(digits) => {
+      let sign_bit = if digits.sign { sign_bit } else { 0 };
+      let (bits, status) = binary_from_digits(&digits, rnd, format);
+      (sign_bit | bits, status)
+    }
+    Scanned::Special(Value::Infinity(sign)) => {
+      let sign_bit = if sign { sign_bit } else { 0 };
+      (sign_bit | infinity(format), Status::NONE)
+    }
+    Scanned::Special(Value::NaN(sign, signaling, payload)) => {
+      // payload is preserved when it fits in the fraction, signaling NaN needs a nonzero fraction
+      let sign_bit = if sign { sign_bit } else { 0 };
+      let max_payload = (1_u128 << (format.precision - 2)) - 1;
+      let payload = if payload <= max_payload { payload as u64 } else { 0 };
+      let bits = if signaling {
+        infinity(format) | payload.max(1)
+      } else {
+        quiet_nan(format) | payload
+      };
+      (sign_bit | bits, Status::NONE)
+    }
+    // finite numbers are never scanned as special values
+    Scanned::Special(Value::Invalid(sign, _) | Value::Finite(sign, ..)) => {
+      let sign_bit = if sign { sign_bit } else { 0 };
+      (sign_bit | quiet_nan(format), Status::INVALID)
+    }
+  }
+}
+
+/// Converts the scanned digits into the bits of the absolute value of the binary floating-point number.
+fn binary_from_digits(digits: &Digits<MAX_BINARY_DIGITS>, rnd: Rounding, format: &BinaryFormat) -> (u64, Status) {
+  if digits.count == 0 {
+    return (0, Status::NONE);
+  }
+  if digits.count <= FAST_PATH_DIGITS {
+    if let Some(result) = eisel_lemire(digits.sign, digits.value as u64, digits.exponent, rnd, format) {
+      return result;
+    }
+  }
+  let mut num = BigUint::from_digits(&digits.buffer[..digits.count]);
+  let mut exponent = digits.buffer_exponent(FAST_PATH_DIGITS);
+  let adjusted = exponent.saturating_add(digits.count as i32 - 1);
+  if digits.truncated {
+    // digits not stored in the buffer are replaced with a single nonzero digit,
+    // the buffer is long enough to make the rounding the same
+    num.mul_small(10);
+    num.add_small(1);
+    exponent = exponent.saturating_sub(1);
+  }
+  round_decimal(digits.sign, num, exponent, adjusted, rnd, format)
+}
+
+/// Rounds the positive value `w * 10^q` to the binary format using the truncated power of five,
+/// returns `None` when the truncation makes the rounding undecidable.
+///
+/// This is the algorithm published by Daniel Lemire, based on the work of Michael Eisel,
+/// extended to report the exact sticky bit needed by all rounding modes.
+fn eisel_lemire(sign: bool, w: u64, q: i32, rnd: Rounding, format: &BinaryFormat) -> Option<(u64, Status)> {
+  if !(POW5_MIN_EXPONENT..=POW5_MAX_EXPONENT).contains(&q) {
+    return None;
+  }
+  let power = POW5_128[(q - POW5_MIN_EXPONENT) as usize];
+  let leading_zeros = w.leading_zeros();
+  let w = w << leading_zeros;
+  // 192-bit product of the normalized coefficient and the power of five
+  let hi = w as u128 * (power >> 64);
+  let lo = w as u128 * (power as u64 as u128);
+  let (mid, carry) = (hi as u64).overflowing_add((lo >> 64) as u64);
+  let mut upper = ((hi >> 64) + carry as u128) << 64 | mid as u128;
+  let mut lower = lo as u64;
+  let mut width = w as u128;
+  // the product has 191 or 192 bits, normalize it to 192 bits
+  let shift = upper.leading_zeros();
+  if shift > 0 {
+    upper = upper << 1 | (lower >> 63) as u128;
+    lower <<= 1;
+    width <<= 1;
+  }
+  let rest = (upper as u64 as u128) << 64 | lower as u128;
+  let sticky = if (0..=POW5_MAX_EXACT_EXPONENT).contains(&q) {
+    // the power of five is exact
+    rest != 0
+  } else {
+    // the exact product is in range [product, product + width), the upper bits are known
+    // only when this range does not contain a multiple of 2^128
+    if rest == 0 || rest.checked_add(width).is_none() {
+      return None;
+    }
+    true
+  };
+  let scale = -(128 + q as i64 + pow5_binary_exponent(q) as i64 - leading_zeros as i64 - shift as i64);
+  Some(round_scaled(sign, (upper >> 64) as u64, sticky, scale, rnd, format))
+}
+
+/// Rounds the positive value `num * 10^exponent` to the binary format,
+/// returns the bits of the absolute value and status flags.
+///
+/// Numbers with `adjusted` exponent far outside the range are replaced with numbers rounded the same way.
+fn round_decimal(
+  sign: bool,
+  num: BigUint,
+  exponent: i32,
+  adjusted: i32,
+  rnd: Rounding,
+  format: &BinaryFormat,
+) -> (u64, Status) {
+  let (mut num, exponent) = if adjusted >= format.huge {
+    (BigUint::from_u128(1), format.huge + 1)
+  } else if adjusted <= format.tiny {
+    (BigUint::from_u128(1), format.tiny - 1)
+  } else {
+    (num, exponent)
+  };
+  let mut den = BigUint::from_u128(1);
+  if exponent >= 0 {
+    num.mul_pow10(exponent as u32);
+  } else {
+    den.mul_pow10(exponent.unsigned_abs());
+  }
+  round_binary(sign, num, den, rnd, format)
+}
+
+/// Returns the sign bit in specified format.
+fn sign_mask(format: &BinaryFormat) -> u64 {
+  let exponent_bits = 64 - (2 * format.emax + 1).leading_zeros() as i64;
+  1 << (format.precision - 1 + exponent_bits)
+}
+
+/// Returns the bits of the positive infinity in specified format.
+fn infinity(format: &BinaryFormat) -> u64 {
+  ((2 * format.emax + 1) as u64) << (format.precision - 1)
+}
+
+/// Returns the bits of the positive quiet NaN in specified format.
+fn quiet_nan(format: &BinaryFormat) -> u64 {
+  infinity(format) | 1 << (format.precision - 2)
+}
+
+/// Rounds the exact quotient `num / den` of two positive integers to the binary format,
+/// returns the bits of the absolute value and status flags.
+fn round_binary(sign: bool, mut num: BigUint, mut den: BigUint, rnd: Rounding, format: &BinaryFormat) -> (u64, Status) {
+  let precision = format.precision;
+  // scale the quotient to have precision + 3 or precision + 4 bits
+  let scale = precision + 3 - (num.bit_len() as i64 - den.bit_len() as i64);
+  if scale >= 0 {
+    num.shl(scale as usize);
+  } else {
+    den.shl(scale.unsigned_abs() as usize);
+  }
+  let (quotient, remainder) = num.div_rem(&den);
+  round_scaled(sign, quotient.low_u64(), !remainder.is_zero(), scale, rnd, format)
+}
+
+/// Rounds the positive value in range `[q, q + 1) * 2^-scale` to the binary format,
+/// `sticky` is `true` when the value is not equal to `q * 2^-scale`,
+/// returns the bits of the absolute value and status flags.
+fn round_scaled(
+  sign: bool,
+  q: u64,
+  mut sticky: bool,
+  scale: i64,
+  rnd: Rounding,
+  format: &BinaryFormat,
+) -> (u64, Status) {
+  let precision = format.precision;
+  let q_bits = 64 - q.leading_zeros() as i64;
+  // the value is in range [2^e, 2^(e+1)), subnormal values keep less bits
+  let e = q_bits - 1 - scale;
+  let keep = if e >= format.emin() {
+    precision
+  } else {
+    precision - (format.emin() - e)
+  };
+  let shift = q_bits - keep;
+  let mut m = q.checked_shr(shift as u32).unwrap_or(0);
+  let round = q.checked_shr((shift - 1) as u32).unwrap_or(0) & 1 == 1;
+  sticky |= match 1_u64.checked_shl((shift - 1) as u32) {
+    Some(half) => q & (half - 1) != 0,
+    None => q != 0,
+  };
+  let mut flags = Status::NONE;
+  if round || sticky {
+    flags |= Status::INEXACT;
+    if e < format.emin() {
+      flags |= Status::UNDERFLOW;
+    }
+  }
+  // the round bit is the half, nonzero bits below make the dropped part greater
+  let half = round.cmp(&true).then(sticky.cmp(&false));
+  let mut exp2 = shift - scale;
+  if rnd.carry(sign, m & 1 == 1, half, round || sticky) {
+    m += 1;
+    // rounding up may produce one bit more than allowed
+    if m >> precision == 1 {
+      m >>= 1;
+      exp2 += 1;
+    }
+  }
+  // biased exponent, zero for subnormal numbers
+  let biased = if m >> (precision - 1) == 1 {
+    exp2 + precision - 1 + format.emax
+  } else {
+    0
+  };
+  if biased > 2 * format.emax {
+    // overflow, the result is either infinity or the largest finite number
+    flags |= Status::OVERFLOW | Status::INEXACT;
+    let to_infinity = match rnd {
+      Rounding::ToNearest | Rounding::TiesAway => true,
+      Rounding::Down => sign,
+      Rounding::Up => !sign,
+      Rounding::ToZero => false,
+    };
+    let bits = if to_infinity {
+      infinity(format)
+    } else {
+      infinity(format) - 1
+    };
+    return (bits, flags);
+  }
+  let fraction = m & ((1 << (precision - 1)) - 1);
+  ((biased as u64) << (precision - 1) | fraction, flags)
+}
+
+/// Converts the binary floating-point number `fraction * 2^exponent` into the nearest decimal.
+fn from_binary(sign: bool, mut fraction: u64, mut exponent: i32, rnd: Rounding) -> (Bid128, Status) {
+  if fraction == 0 {
+    return bid128_from_value(Value::Finite(sign, 0, 0, Status::NONE));
+  }
+  // remove trailing zero bits to get the smallest exact decimal exponent
+  let zeros = fraction.trailing_zeros();
+  fraction >>= zeros;
+  exponent += zeros as i32;
+  let mut digits = BigUint::from_u128(fraction as u128);
+  if exponent >= 0 {
+    digits.shl(exponent as usize);
+    exponent = 0;
+  } else {
+    // fraction * 2^-n = fraction * 5^n * 10^-n
+    digits.mul_pow5(exponent.unsigned_abs());
+  }
+  round_result(sign, &digits, exponent as i64, false, rnd)
+}
+
+/// Converts the binary NaN into decimal NaN, signaling NaN is reported as invalid.
+fn from_binary_nan(sign: bool, signaling: bool) -> (Bid128, Status) {
+  let (nan, _) = bid128_from_value(Value::NaN(sign, false, 0));
+  let status = if signaling { Status::INVALID } else { Status::NONE };
+  (nan, status)
+}
@@ -0,0 +1,169 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Comparisons of decimals
+
+use crate::bid128::Bid128;
+use crate::recognizer::Value;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+impl Bid128 {
+  /// Returns the ordering between this decimal and other decimal
+  /// defined by the IEEE 754 `totalOrder` predicate.
+  ///
+  /// Negative values are ordered before positive values, also for zeros and NaNs.
+  /// Members of the same cohort are ordered by exponent, positive numbers with smaller exponent first.
+  /// Positive NaNs are ordered after infinity, signaling NaNs before quiet NaNs and then by payload.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use core::cmp::Ordering;
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (a, _) = bid128_from_string("1.00");
+  /// let (b, _) = bid128_from_string("1.0");
+  /// assert_eq!(Ordering::Less, a.total_cmp(&b));
+  ///
+  /// let (a, _) = bid128_from_string("-0");
+  /// let (b, _) = bid128_from_string("0");
+  /// assert_eq!(Ordering::Less, a.total_cmp(&b));
+  /// ```
+  pub fn total_cmp(&self, other: &Self) -> Ordering {
+    match (self.is_sign_negative(), other.is_sign_negative()) {
+      (true, false) => Ordering::Less,
+      (false, true) => Ordering::Greater,
+      (false, false) => total_cmp_magnitude(&self.unpack(), &other.unpack()),
+      (true, true) => total_cmp_magnitude(&self.unpack(), &other.unpack()).reverse(),
+    }
+  }
+}
+
+impl PartialEq for Bid128 {
+  /// Returns `true` when both decimals are numerically equal,
+  /// members of the same cohort and zeros with different signs are equal, NaNs are never equal.
+  fn eq(&self, other: &Self) -> bool {
+    self.partial_cmp(other) == Some(Ordering::Equal)
+  }
+}
+
+impl PartialOrd for Bid128 {
+  /// Returns the numerical ordering of decimals, `None` when any of them is NaN.
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    match (self.unpack(), other.unpack()) {
+      (Value::Finite(sign_a, coefficient_a, exponent_a, _), Value::Finite(sign_b, coefficient_b, exponent_b, _)) => {
+        // zeros are equal regardless of the sign
+        let sign_a = sign_a && coefficient_a != 0;
+        let sign_b = sign_b && coefficient_b != 0;
+        Some(match (sign_a, sign_b) {
+          (true, false) => Ordering::Less,
+          (false, true) => Ordering::Greater,
+          (false, false) => cmp_magnitude(coefficient_a, exponent_a, coefficient_b, exponent_b),
+          (true, true) => cmp_magnitude(coefficient_a, exponent_a, coefficient_b, exponent_b).reverse(),
+        })
+      }
+      (Value::Infinity(sign_a), Value::Infinity(sign_b)) => Some(sign_b.cmp(&sign_a)),
+      (Value::Infinity(sign), Value::Finite(..)) => Some(if sign { Ordering::Less } else { Ordering::Greater }),
+      (Value::Finite(..), Value::Infinity(sign)) => Some(if sign { Ordering::Greater } else { Ordering::Less }),
+      _ => None,
+    }
+  }
+}
+
+impl Hash for Bid128 {
+  /// Hashes the numerical value, so numerically equal decimals have equal hashes.
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self.unpack() {
+      Value::Finite(_, 0, _, _) => state.write_u8(0),
+      Value::Finite(sign, mut coefficient, mut exponent, _) => {
+        // members of the same cohort are hashed like the one with the largest exponent
+        while coefficient % 10 == 0 {
+          coefficient /= 10;
+          exponent += 1;
+        }
+        state.write_u8(1);
+        sign.hash(state);
+        coefficient.hash(state);
+        exponent.hash(state);
+      }
+      Value::Infinity(sign) => {
+        state.write_u8(2);
+        sign.hash(state);
+      }
+      Value::NaN(sign, signaling, payload) => {
+        state.write_u8(3);
+        sign.hash(state);
+        signaling.hash(state);
+        payload.hash(state);
+      }
+      Value::Invalid(..) => state.write_u8(4),
+    }
+  }
+}
+
+/// Compares magnitudes of two finite decimals, zeros are equal regardless of the exponent.
+pub(crate) fn cmp_magnitude(coefficient_a: u128, exponent_a: i32, coefficient_b: u128, exponent_b: i32) -> Ordering {
+  match (coefficient_a, coefficient_b) {
+    (0, 0) => return Ordering::Equal,
+    (0, _) => return Ordering::Less,
+    (_, 0) => return Ordering::Greater,
+    _ => {}
+  }
+  // compare adjusted exponents first
+  let adjusted_a = exponent_a + coefficient_a.ilog10() as i32;
+  let adjusted_b = exponent_b + coefficient_b.ilog10() as i32;
+  if adjusted_a != adjusted_b {
+    return adjusted_a.cmp(&adjusted_b);
+  }
+  // with equal adjusted exponents, the coefficient with larger exponent has less digits,
+  // so it can be aligned without overflow
+  if exponent_a >= exponent_b {
+    (coefficient_a * 10_u128.pow((exponent_a - exponent_b) as u32)).cmp(&coefficient_b)
+  } else {
+    coefficient_a.cmp(&(coefficient_b * 10_u128.pow((exponent_b - exponent_a) as u32)))
+  }
+}
+
+/// Compares magnitudes of two decimals according to the IEEE 754 `totalOrder` predicate.
+fn total_cmp_magnitude(a: &Value, b: &Value) -> Ordering {
+  /// Returns the rank of the kind of value, finite numbers first.
+  fn rank(value: &Value) -> u8 {
+    match value {
+      Value::Finite(..) => 0,
+      Value::Infinity(_) => 1,
+      Value::NaN(_, true, _) => 2,
+      Value::NaN(_, false, _) | Value::Invalid(..) => 3,
+    }
+  }
+  match (a, b) {
+    (Value::Finite(_, coefficient_a, exponent_a, _), Value::Finite(_, coefficient_b, exponent_b, _)) => {
+      cmp_magnitude(*coefficient_a, *exponent_a, *coefficient_b, *exponent_b).then(exponent_a.cmp(exponent_b))
+    }
+    (Value::NaN(_, signaling_a, payload_a), Value::NaN(_, signaling_b, payload_b)) if signaling_a == signaling_b => {
+      payload_a.cmp(payload_b)
+    }
+    _ => rank(a).cmp(&rank(b)),
+  }
+}
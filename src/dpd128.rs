@@ -0,0 +1,298 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Densely packed decimal encoding of 128-bit floating-point decimals.
+
+use crate::Bid128;
+
+/// 128-bit decimal in densely packed decimal format.
+#[derive(Copy, Clone)]
+pub struct Dpd128 {
+  pub w: [u64; 2],
+}
+
+const SIGN: u64 = 0x8000000000000000;
+
+const INF: u64 = 0x7800000000000000;
+
+const NAN: u64 = 0x7c00000000000000;
+
+const SNAN: u64 = 0x7e00000000000000;
+
+/// Mask of the combination field bits that identify infinities and NaNs.
+const SPECIAL_MASK: u64 = 0x7800000000000000;
+
+/// Mask of the combination field bits that identify NaNs.
+const NAN_MASK: u64 = 0x7c00000000000000;
+
+/// Mask of the bit that identifies signaling NaNs.
+const SNAN_MASK: u64 = 0x0200000000000000;
+
+/// Mask of the steering bits in binary integer decimal format.
+const BID_STEERING_MASK: u64 = 0x6000000000000000;
+
+/// Mask of the higher bits of the coefficient in binary integer decimal format.
+const BID_COEFFICIENT_MASK: u64 = 0x0001ffffffffffff;
+
+/// Mask of the higher bits of the trailing significand.
+const TRAILING_MASK: u64 = 0x00003fffffffffff;
+
+/// Number of declets in the trailing significand.
+const DECLETS: u32 = 11;
+
+/// Coefficients greater than or equal to this value are non-canonical.
+const COEFFICIENT_LIMIT: u128 = 10_000_000_000_000_000_000_000_000_000_000_000;
+
+/// Value of the most significant digit of the coefficient.
+const MSD_WEIGHT: u128 = 1_000_000_000_000_000_000_000_000_000_000_000;
+
+impl From<Bid128> for Dpd128 {
+  /// Converts [Bid128] into [Dpd128].
+  fn from(value: Bid128) -> Self {
+    let [w0, w1] = value.w;
+    let s = w1 & SIGN;
+    if w1 & SPECIAL_MASK == SPECIAL_MASK {
+      if w1 & NAN_MASK == NAN_MASK {
+        let mut payload = ((w1 & TRAILING_MASK) as u128) << 64 | w0 as u128;
+        if payload >= MSD_WEIGHT {
+          payload = 0;
+        }
+        let trailing = encode_trailing(payload);
+        let n = if w1 & SNAN_MASK == 0 { NAN } else { SNAN };
+        return Dpd128 {
+          w: [trailing as u64, s | n | (trailing >> 64) as u64],
+        };
+      }
+      return Dpd128 { w: [0, s | INF] };
+    }
+    let (exponent, mut coefficient) = if w1 & BID_STEERING_MASK == BID_STEERING_MASK {
+      // coefficients in this form are always greater than the maximum, so they are non-canonical
+      ((w1 >> 47) & 0x3fff, 0)
+    } else {
+      (
+        (w1 >> 49) & 0x3fff,
+        ((w1 & BID_COEFFICIENT_MASK) as u128) << 64 | w0 as u128,
+      )
+    };
+    if coefficient >= COEFFICIENT_LIMIT {
+      coefficient = 0;
+    }
+    let msd = (coefficient / MSD_WEIGHT) as u64;
+    let trailing = encode_trailing(coefficient % MSD_WEIGHT);
+    let combination = if msd < 8 {
+      (exponent >> 12) << 3 | msd
+    } else {
+      0x18 | (exponent >> 12) << 1 | (msd & 1)
+    };
+    Dpd128 {
+      w: [
+        trailing as u64,
+        s | combination << 58 | (exponent & 0xfff) << 46 | (trailing >> 64) as u64,
+      ],
+    }
+  }
+}
+
+impl From<Dpd128> for Bid128 {
+  /// Converts [Dpd128] into [Bid128].
+  fn from(value: Dpd128) -> Self {
+    let [w0, w1] = value.w;
+    let s = w1 & SIGN;
+    let trailing = decode_trailing(((w1 & TRAILING_MASK) as u128) << 64 | w0 as u128);
+    if w1 & SPECIAL_MASK == SPECIAL_MASK {
+      if w1 & NAN_MASK == NAN_MASK {
+        let n = if w1 & SNAN_MASK == 0 { NAN } else { SNAN };
+        return Bid128 {
+          w: [trailing as u64, s | n | (trailing >> 64) as u64],
+        };
+      }
+      return Bid128 { w: [0, s | INF] };
+    }
+    let combination = (w1 >> 58) & 0x1f;
+    let (top, msd) = if combination >> 3 == 0x3 {
+      ((combination >> 1) & 0x3, 8 | (combination & 1))
+    } else {
+      (combination >> 3, combination & 0x7)
+    };
+    let exponent = top << 12 | (w1 >> 46) & 0xfff;
+    let coefficient = msd as u128 * MSD_WEIGHT + trailing;
+    Bid128 {
+      w: [coefficient as u64, s | exponent << 49 | (coefficient >> 64) as u64],
+    }
+  }
+}
+
+/// Encodes the value (less than 10^33) as a sequence of declets.
+fn encode_trailing(mut value: u128) -> u128 {
+  let mut trailing = 0_u128;
+  for i in 0..DECLETS {
+    trailing |= (encode_declet((value % 1000) as u16) as u128) << (10 * i);
+    value /= 1000;
+  }
+  trailing
+}
+
+/// Decodes the value from a sequence of declets.
+fn decode_trailing(trailing: u128) -> u128 {
+  let mut value = 0_u128;
+  for i in (0..DECLETS).rev() {
+    value = value * 1000 + decode_declet(((trailing >> (10 * i)) & 0x3ff) as u16) as u128;
+  }
+  value
+}
+
+/// Encodes three decimal digits (value less than 1000) into a declet.
+fn encode_declet(value: u16) -> u16 {
+  let d2 = value / 100;
+  let d1 = value / 10 % 10;
+  let d0 = value % 10;
+  match (d2 > 7, d1 > 7, d0 > 7) {
+    (false, false, false) => d2 << 7 | d1 << 4 | d0,
+    (false, false, true) => d2 << 7 | d1 << 4 | 0b1000 | (d0 & 1),
+    (false, true, false) => d2 << 7 | (d0 & 0b110) << 4 | (d1 & 1) << 4 | 0b1010 | (d0 & 1),
+    (false, true, true) => d2 << 7 | 0b1000000 | (d1 & 1) << 4 | 0b1110 | (d0 & 1),
+    (true, false, false) => (d0 & 0b110) << 7 | (d2 & 1) << 7 | d1 << 4 | 0b1100 | (d0 & 1),
+    (true, false, true) => (d1 & 0b110) << 7 | (d2 & 1) << 7 | 0b0100000 | (d1 & 1) << 4 | 0b1110 | (d0 & 1),
+    (true, true, false) => (d0 & 0b110) << 7 | (d2 & 1) << 7 | (d1 & 1) << 4 | 0b1110 | (d0 & 1),
+    (true, true, true) => (d2 & 1) << 7 | 0b1100000 | (d1 & 1) << 4 | 0b1110 | (d0 & 1),
+  }
+}
+
+/// Decodes three decimal digits from a declet.
+fn decode_declet(declet: u16) -> u16 {
+  let pqr = declet >> 7;
+  let stu = (declet >> 4) & 0b111;
+  let wxy = declet & 0b111;
+  let (d2, d1, d0) = if declet & 0b1000 == 0 {
+    (pqr, stu, wxy)
+  } else {
+    match (wxy >> 1, stu >> 1) {
+      (0b00, _) => (pqr, stu, 8 | (wxy & 1)),
+      (0b01, _) => (pqr, 8 | (stu & 1), (stu & 0b110) | (wxy & 1)),
+      (0b10, _) => (8 | (pqr & 1), stu, (pqr & 0b110) | (wxy & 1)),
+      (_, 0b10) => (pqr, 8 | (stu & 1), 8 | (wxy & 1)),
+      (_, 0b01) => (8 | (pqr & 1), (pqr & 0b110) | (stu & 1), 8 | (wxy & 1)),
+      (_, 0b00) => (8 | (pqr & 1), 8 | (stu & 1), (pqr & 0b110) | (wxy & 1)),
+      _ => (8 | (pqr & 1), 8 | (stu & 1), 8 | (wxy & 1)),
+    }
+  };
+  d2 * 100 + d1 * 10 + d0
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::bid128_from_string;
+
+  fn to_dpd(input: &str) -> [u64; 2] {
+    Dpd128::from(bid128_from_string(input).0).w
+  }
+
+  #[test]
+  fn test_declets() {
+    for value in 0..1000 {
+      let declet = encode_declet(value);
+      assert!(declet < 0x400, "{value}");
+      assert_eq!(value, decode_declet(declet), "{value}");
+    }
+    assert_eq!(0x000, encode_declet(0));
+    assert_eq!(0x010, encode_declet(10));
+    assert_eq!(0x0ff, encode_declet(999));
+    assert_eq!(0x0a3, encode_declet(123));
+  }
+
+  #[test]
+  fn test_non_canonical_declets() {
+    assert_eq!(999, decode_declet(0x3ff));
+    assert_eq!(888, decode_declet(0x36e));
+    assert_eq!(999, decode_declet(0x1ff));
+  }
+
+  #[test]
+  fn test_bid_to_dpd() {
+    assert_eq!([0x0000000000000000, 0x2208000000000000], to_dpd("0"));
+    assert_eq!([0x0000000000000001, 0x2208000000000000], to_dpd("1"));
+    assert_eq!([0x0000000000000001, 0xa208000000000000], to_dpd("-1"));
+    assert_eq!([0x0000000000000010, 0x2207c00000000000], to_dpd("1.0"));
+    assert_eq!(
+      [0xf3fcff3fcff3fcff, 0x77ffcff3fcff3fcf],
+      to_dpd("9.999999999999999999999999999999999E+6144")
+    );
+    assert_eq!([0x0000000000000000, 0x7800000000000000], to_dpd("inf"));
+    assert_eq!([0x0000000000000000, 0xf800000000000000], to_dpd("-inf"));
+    assert_eq!([0x0000000000000000, 0x7c00000000000000], to_dpd("NaN"));
+    assert_eq!([0x0000000000000000, 0xfe00000000000000], to_dpd("-sNaN"));
+    assert_eq!([0x00000000000000a3, 0x7c00000000000000], to_dpd("NaN123"));
+  }
+
+  #[test]
+  fn test_non_canonical_bid_to_dpd() {
+    let dpd = Dpd128::from(Bid128 {
+      w: [0xffffffffffffffff, 0x3041ffffffffffff],
+    });
+    assert_eq!([0x0000000000000000, 0x2208000000000000], dpd.w);
+    let dpd = Dpd128::from(Bid128 {
+      w: [0xffffffffffffffff, 0x6c107fffffffffff],
+    });
+    assert_eq!([0x0000000000000000, 0x2208000000000000], dpd.w);
+  }
+
+  #[test]
+  fn test_dpd_to_bid() {
+    let bid = Bid128::from(Dpd128 {
+      w: [0x0000000000000010, 0x2207c00000000000],
+    });
+    assert_eq!([0x000000000000000a, 0x303e000000000000], bid.w);
+    let bid = Bid128::from(Dpd128 {
+      w: [0xf3fcff3fcff3fcff, 0xf7ffcff3fcff3fcf],
+    });
+    assert_eq!([0x378d8e63ffffffff, 0xdfffed09bead87c0], bid.w);
+  }
+
+  #[test]
+  fn test_round_trip() {
+    for input in [
+      "0",
+      "-0e-6176",
+      "0e+6111",
+      "1",
+      "1234567890123456789012345678901234",
+      "-8765432109876543210987654321098765e-6176",
+      "8888888888888888888888888888888888e+6111",
+      "9999999999999999999999999999999999",
+      "0.00003E-02",
+      "inf",
+      "-inf",
+      "NaN",
+      "-sNaN",
+      "NaN123",
+      "-sNaN999999999999999999999999999999999",
+    ] {
+      let bid = bid128_from_string(input).0;
+      let expected = bid.w;
+      let actual = Bid128::from(Dpd128::from(bid)).w;
+      assert_eq!(expected, actual, "{input}");
+    }
+  }
+}
@@ -0,0 +1,215 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Errors reported by parsers
+
+use crate::Status;
+use core::fmt;
+
+/// Kinds of errors reported when the text is not a valid number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+  /// The text is empty or contains only whitespace.
+  Empty,
+  /// The text contains a character that is not allowed at this position.
+  UnexpectedCharacter,
+  /// The text ends before the number is complete.
+  UnexpectedEnd,
+  /// The exponent marker is not followed by any digit.
+  MissingExponentDigits,
+  /// The digit group separators are placed at wrong positions.
+  InvalidGrouping,
+  /// The decimal mark or the group separator can not be distinguished from other parts of the number.
+  InvalidOptions,
+}
+
+/// Error reported when the text is not a valid number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+  /// Kind of the error.
+  kind: ParseErrorKind,
+  /// Byte offset in the text where the error was detected.
+  offset: usize,
+}
+
+impl ParseError {
+  /// Creates a new parsing error.
+  pub(crate) fn new(kind: ParseErrorKind, offset: usize) -> Self {
+    Self { kind, offset }
+  }
+
+  /// Returns the kind of the error.
+  pub fn kind(&self) -> ParseErrorKind {
+    self.kind
+  }
+
+  /// Returns the byte offset in the text where the error was detected.
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+}
+
+impl fmt::Display for ParseError {
+  /// Formats the description of the error.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.kind {
+      ParseErrorKind::Empty => write!(f, "empty input"),
+      ParseErrorKind::UnexpectedCharacter => write!(f, "unexpected character at offset {}", self.offset),
+      ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input at offset {}", self.offset),
+      ParseErrorKind::MissingExponentDigits => write!(f, "missing exponent digits at offset {}", self.offset),
+      ParseErrorKind::InvalidGrouping => write!(f, "invalid digit grouping at offset {}", self.offset),
+      ParseErrorKind::InvalidOptions => write!(f, "conflicting decimal mark and group separator"),
+    }
+  }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Error reported when converting text into a number fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FromStrError {
+  /// The text is not a valid number.
+  Syntax(ParseError),
+  /// The number is too large to be represented.
+  Overflow,
+  /// The number is too small to be represented.
+  Underflow,
+  /// The number can not be represented exactly.
+  Inexact,
+}
+
+impl fmt::Display for FromStrError {
+  /// Formats the description of the error.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FromStrError::Syntax(err) => write!(f, "invalid number: {}", err),
+      FromStrError::Overflow => write!(f, "number too large"),
+      FromStrError::Underflow => write!(f, "number too small"),
+      FromStrError::Inexact => write!(f, "number not exact"),
+    }
+  }
+}
+
+impl core::error::Error for FromStrError {
+  /// Returns the syntax error that caused this error.
+  fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+    match self {
+      FromStrError::Syntax(err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl From<ParseError> for FromStrError {
+  /// Converts [ParseError] into [FromStrError].
+  fn from(value: ParseError) -> Self {
+    FromStrError::Syntax(value)
+  }
+}
+
+/// Error reported when a binary floating-point number can not be converted into a decimal exactly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TryFromFloatError {
+  /// Status flags raised by the conversion.
+  status: Status,
+}
+
+impl TryFromFloatError {
+  /// Creates a new conversion error.
+  pub(crate) fn new(status: Status) -> Self {
+    Self { status }
+  }
+
+  /// Returns the status flags raised by the conversion.
+  pub fn status(&self) -> Status {
+    self.status
+  }
+}
+
+impl fmt::Display for TryFromFloatError {
+  /// Formats the description of the error.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.status.is_invalid() {
+      write!(f, "signaling NaN can not be converted")
+    } else {
+      write!(f, "number not exact")
+    }
+  }
+}
+
+impl core::error::Error for TryFromFloatError {}
+
+/// Policy deciding which exceptional results of converting text into a number are reported as errors.
+///
+/// When not reported as errors, overflow results in an infinity, underflow results in a zero
+/// and inexact results are rounded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RangePolicy {
+  /// When `true`, overflow is reported as [FromStrError::Overflow].
+  pub reject_overflow: bool,
+  /// When `true`, underflow is reported as [FromStrError::Underflow].
+  pub reject_underflow: bool,
+  /// When `true`, rounding is reported as [FromStrError::Inexact].
+  pub reject_inexact: bool,
+}
+
+impl RangePolicy {
+  /// Policy accepting all results, like the parsing functions do.
+  pub const ACCEPT_ALL: RangePolicy = RangePolicy {
+    reject_overflow: false,
+    reject_underflow: false,
+    reject_inexact: false,
+  };
+
+  /// Policy rejecting all results that are not exactly equal to the parsed text.
+  pub const EXACT: RangePolicy = RangePolicy {
+    reject_overflow: true,
+    reject_underflow: true,
+    reject_inexact: true,
+  };
+
+  /// Checks the status flags against this policy.
+  pub(crate) fn check(&self, status: Status) -> Result<(), FromStrError> {
+    if self.reject_overflow && status.is_overflow() {
+      Err(FromStrError::Overflow)
+    } else if self.reject_underflow && status.is_underflow() {
+      Err(FromStrError::Underflow)
+    } else if self.reject_inexact && status.is_inexact() {
+      Err(FromStrError::Inexact)
+    } else {
+      Ok(())
+    }
+  }
+}
+
+impl Default for RangePolicy {
+  /// Default policy rejects overflow and underflow, inexact results are rounded.
+  fn default() -> Self {
+    Self {
+      reject_overflow: true,
+      reject_underflow: true,
+      reject_inexact: false,
+    }
+  }
+}
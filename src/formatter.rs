@@ -0,0 +1,302 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Formatter for numbers in scientific notation.
+
+use crate::recognizer::Value;
+use core::fmt;
+
+/// Maximum number of digits in a coefficient.
+const MAX_COEFFICIENT_DIGITS: usize = 39;
+
+/// Maximum length of the text of a number, with the longest coefficient and exponent.
+const MAX_TEXT_LENGTH: usize = 64;
+
+/// Buffer collecting the text of a number before it is padded.
+struct TextBuffer {
+  bytes: [u8; MAX_TEXT_LENGTH],
+  len: usize,
+}
+
+impl fmt::Write for TextBuffer {
+  /// Appends the text, fails when the buffer is full.
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    let end = self.len + s.len();
+    if end > MAX_TEXT_LENGTH {
+      return Err(fmt::Error);
+    }
+    self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+    self.len = end;
+    Ok(())
+  }
+}
+
+/// Writes the value using the to-scientific-string rules,
+/// padded to the width with the fill and alignment requested by the formatter.
+pub fn pad_scientific(f: &mut fmt::Formatter<'_>, value: &Value) -> fmt::Result {
+  let mut buffer = TextBuffer {
+    bytes: [0; MAX_TEXT_LENGTH],
+    len: 0,
+  };
+  write_scientific(&mut buffer, value)?;
+  // only ASCII characters are written
+  f.pad(core::str::from_utf8(&buffer.bytes[..buffer.len]).map_err(|_| fmt::Error)?)
+}
+
+/// Writes the value using the to-scientific-string rules
+/// defined by the General Decimal Arithmetic specification.
+pub fn write_scientific(f: &mut impl fmt::Write, value: &Value) -> fmt::Result {
+  write_value(f, value, false)
+}
+
+/// Writes the value using the to-engineering-string rules
+/// defined by the General Decimal Arithmetic specification.
+pub fn write_engineering(f: &mut impl fmt::Write, value: &Value) -> fmt::Result {
+  write_value(f, value, true)
+}
+
+/// Writes the value in scientific or engineering notation.
+fn write_value(f: &mut impl fmt::Write, value: &Value, engineering: bool) -> fmt::Result {
+  match value {
+    Value::Finite(sign, coefficient, exponent, _) => {
+      if *sign {
+        f.write_char('-')?;
+      }
+      let mut buffer = [0_u8; MAX_COEFFICIENT_DIGITS];
+      let digits = coefficient_digits(*coefficient, &mut buffer);
+      let len = digits.len() as i64;
+      // number of digits before the decimal point when no exponent is written
+      let left_digits = *exponent as i64 + len;
+      let point = if *exponent <= 0 && left_digits > -6 {
+        // plain notation, without exponent
+        left_digits
+      } else if !engineering {
+        1
+      } else if *coefficient == 0 {
+        // zero gets the exponent raised to the next multiple of three
+        (left_digits + 1).rem_euclid(3) - 1
+      } else {
+        (left_digits - 1).rem_euclid(3) + 1
+      };
+      if point <= 0 {
+        f.write_str("0.")?;
+        for _ in point..0 {
+          f.write_char('0')?;
+        }
+        write_digits(f, digits)?;
+      } else if point >= len {
+        write_digits(f, digits)?;
+        for _ in len..point {
+          f.write_char('0')?;
+        }
+      } else {
+        write_digits(f, &digits[..point as usize])?;
+        f.write_char('.')?;
+        write_digits(f, &digits[point as usize..])?;
+      }
+      if left_digits != point {
+        write_exponent(f, left_digits - point)?;
+      }
+      Ok(())
+    }
+    Value::Infinity(sign) => {
+      if *sign {
+        f.write_char('-')?;
+      }
+      f.write_str("Infinity")
+    }
+    Value::NaN(sign, signaling, payload) => {
+      if *sign {
+        f.write_char('-')?;
+      }
+      if *signaling {
+        f.write_char('s')?;
+      }
+      f.write_str("NaN")?;
+      if *payload > 0 {
+        let mut buffer = [0_u8; MAX_COEFFICIENT_DIGITS];
+        write_digits(f, coefficient_digits(*payload, &mut buffer))?;
+      }
+      Ok(())
+    }
+    Value::Invalid(sign, _) => {
+      if *sign {
+        f.write_char('-')?;
+      }
+      f.write_str("NaN")
+    }
+  }
+}
+
+/// Stores decimal digits of the coefficient in the buffer, returns the slice containing all digits.
+fn coefficient_digits(mut coefficient: u128, buffer: &mut [u8; MAX_COEFFICIENT_DIGITS]) -> &[u8] {
+  let mut start = MAX_COEFFICIENT_DIGITS;
+  loop {
+    start -= 1;
+    buffer[start] = (coefficient % 10) as u8;
+    coefficient /= 10;
+    if coefficient == 0 {
+      break;
+    }
+  }
+  &buffer[start..]
+}
+
+/// Writes decimal digits.
+fn write_digits(f: &mut impl fmt::Write, digits: &[u8]) -> fmt::Result {
+  for digit in digits {
+    f.write_char((b'0' + digit) as char)?;
+  }
+  Ok(())
+}
+
+/// Writes the exponent, always preceded by a sign.
+fn write_exponent(f: &mut impl fmt::Write, exponent: i64) -> fmt::Result {
+  if exponent < 0 {
+    write!(f, "E-{}", -exponent)
+  } else {
+    write!(f, "E+{}", exponent)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Status;
+  use alloc::format;
+  use alloc::string::String;
+
+  fn sci(sign: bool, coefficient: u128, exponent: i32) -> String {
+    let mut s = String::new();
+    write_scientific(&mut s, &Value::Finite(sign, coefficient, exponent, Status::NONE)).unwrap();
+    s
+  }
+
+  #[test]
+  fn test_scientific() {
+    assert_eq!("123", sci(false, 123, 0));
+    assert_eq!("-123", sci(true, 123, 0));
+    assert_eq!("1.23E+3", sci(false, 123, 1));
+    assert_eq!("1.23E+5", sci(false, 123, 3));
+    assert_eq!("12.3", sci(false, 123, -1));
+    assert_eq!("0.00123", sci(false, 123, -5));
+    assert_eq!("1.23E-8", sci(false, 123, -10));
+    assert_eq!("-1.23E-10", sci(true, 123, -12));
+    assert_eq!("0", sci(false, 0, 0));
+    assert_eq!("0.00", sci(false, 0, -2));
+    assert_eq!("0E+2", sci(false, 0, 2));
+    assert_eq!("-0", sci(true, 0, 0));
+    assert_eq!("0.000005", sci(false, 5, -6));
+    assert_eq!("0.0000050", sci(false, 50, -7));
+    assert_eq!("5E-7", sci(false, 5, -7));
+    assert_eq!("0E-6176", sci(false, 0, -6176));
+    assert_eq!(
+      "9.999999999999999999999999999999999E+6144",
+      sci(false, 9999999999999999999999999999999999, 6111)
+    );
+  }
+
+  fn eng(sign: bool, coefficient: u128, exponent: i32) -> String {
+    let mut s = String::new();
+    write_engineering(&mut s, &Value::Finite(sign, coefficient, exponent, Status::NONE)).unwrap();
+    s
+  }
+
+  #[test]
+  fn test_engineering() {
+    assert_eq!("123", eng(false, 123, 0));
+    assert_eq!("-123", eng(true, 123, 0));
+    assert_eq!("1.23E+3", eng(false, 123, 1));
+    assert_eq!("123E+3", eng(false, 123, 3));
+    assert_eq!("12.3E-9", eng(false, 123, -10));
+    assert_eq!("-123E-12", eng(true, 123, -12));
+    assert_eq!("700E-9", eng(false, 7, -7));
+    assert_eq!("70", eng(false, 7, 1));
+    assert_eq!("10E+6", eng(false, 1, 7));
+    assert_eq!("12.34567E+15", eng(false, 1234567, 10));
+    assert_eq!("0.000005", eng(false, 5, -6));
+    assert_eq!("0.00", eng(false, 0, -2));
+    assert_eq!("10E-6177", eng(false, 1, -6176));
+  }
+
+  #[test]
+  fn test_engineering_zero() {
+    assert_eq!("0.00E+3", eng(false, 0, 1));
+    assert_eq!("0.0E+3", eng(false, 0, 2));
+    assert_eq!("0E+3", eng(false, 0, 3));
+    assert_eq!("0.0E-6", eng(false, 0, -7));
+    assert_eq!("0.00E-6", eng(false, 0, -8));
+    assert_eq!("0E-9", eng(false, 0, -9));
+    assert_eq!("0.0E-9", eng(false, 0, -10));
+    assert_eq!("-0.00E+6", eng(true, 0, 4));
+  }
+
+  #[test]
+  fn test_special() {
+    let mut s = String::new();
+    write_scientific(&mut s, &Value::Infinity(false)).unwrap();
+    write_scientific(&mut s, &Value::Infinity(true)).unwrap();
+    write_scientific(&mut s, &Value::NaN(false, false, 0)).unwrap();
+    write_scientific(&mut s, &Value::NaN(true, true, 0)).unwrap();
+    assert_eq!("Infinity-InfinityNaN-sNaN", s);
+  }
+
+  #[test]
+  fn test_payload() {
+    let mut s = String::new();
+    write_scientific(&mut s, &Value::NaN(false, false, 123)).unwrap();
+    write_engineering(&mut s, &Value::NaN(true, true, 4560)).unwrap();
+    assert_eq!("NaN123-sNaN4560", s);
+  }
+
+  /// Value formatted with padding requested by the format string.
+  struct Padded(Value);
+
+  impl fmt::Display for Padded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      pad_scientific(f, &self.0)
+    }
+  }
+
+  #[test]
+  fn test_padding() {
+    let value = Padded(Value::Finite(true, 15, -1, Status::NONE));
+    assert_eq!("-1.5", format!("{}", value));
+    assert_eq!("  -1.5", format!("{:>6}", value));
+    assert_eq!("-1.5__", format!("{:_<6}", value));
+    assert_eq!(" -1.5 ", format!("{:^6}", value));
+    assert_eq!("  -Infinity", format!("{:>11}", Padded(Value::Infinity(true))));
+    // the longest texts fit in the buffer
+    for value in [
+      Value::Finite(true, u128::MAX, i32::MIN, Status::NONE),
+      Value::Finite(true, u128::MAX, i32::MAX, Status::NONE),
+      Value::Finite(true, u128::MAX, -44, Status::NONE),
+      Value::NaN(true, true, u128::MAX),
+    ] {
+      let mut expected = String::new();
+      write_scientific(&mut expected, &value).unwrap();
+      assert_eq!(expected, format!("{}", Padded(value)));
+    }
+  }
+}
@@ -0,0 +1,172 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Conversions between decimals and integers
+
+use crate::bid128::{bid128_from_value, Bid128, BID128_NAX_DIGITS};
+use crate::quantize::round_to_exponent;
+use crate::recognizer::{Rounding, Value};
+use crate::Status;
+
+/// Maximum number of digits in a coefficient.
+const MAX_DIGITS: u32 = BID128_NAX_DIGITS as u32;
+
+impl Bid128 {
+  /// Converts this decimal into [i64], with rounding mode.
+  ///
+  /// NaNs, infinities and values out of range after rounding are converted into
+  /// the integer indefinite value `i64::MIN` and reported as invalid.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding, Status};
+  ///
+  /// let (value, _) = bid128_from_string("-2.5");
+  /// assert_eq!((-2, Status::INEXACT), value.to_i64_rnd(Rounding::ToNearest));
+  /// assert_eq!((-3, Status::INEXACT), value.to_i64_rnd(Rounding::Down));
+  ///
+  /// let (value, _) = bid128_from_string("1e19");
+  /// assert_eq!((i64::MIN, Status::INVALID), value.to_i64_rnd(Rounding::ToNearest));
+  /// ```
+  pub fn to_i64_rnd(&self, rnd: Rounding) -> (i64, Status) {
+    match self.round_to_integer(rnd) {
+      Some((false, value, status)) if value <= i64::MAX as u128 => (value as i64, status),
+      Some((true, value, status)) if value <= i64::MIN.unsigned_abs() as u128 => {
+        ((value as i64).wrapping_neg(), status)
+      }
+      _ => (i64::MIN, Status::INVALID),
+    }
+  }
+
+  /// Converts this decimal into [u64], with rounding mode.
+  ///
+  /// Negative values rounded to zero are converted into zero,
+  /// other values out of range are converted like in [Bid128::to_i64_rnd].
+  pub fn to_u64_rnd(&self, rnd: Rounding) -> (u64, Status) {
+    match self.round_to_integer(rnd) {
+      Some((sign, value, status)) if value <= u64::MAX as u128 && (!sign || value == 0) => (value as u64, status),
+      _ => (i64::MIN as u64, Status::INVALID),
+    }
+  }
+
+  /// Converts this decimal into [i128], with rounding mode.
+  ///
+  /// Values out of range are converted into `i128::MIN` like in [Bid128::to_i64_rnd].
+  pub fn to_i128_rnd(&self, rnd: Rounding) -> (i128, Status) {
+    match self.round_to_integer(rnd) {
+      Some((false, value, status)) if value <= i128::MAX as u128 => (value as i128, status),
+      Some((true, value, status)) if value <= i128::MIN.unsigned_abs() => ((value as i128).wrapping_neg(), status),
+      _ => (i128::MIN, Status::INVALID),
+    }
+  }
+
+  /// Converts this decimal into [i64], fails with status flags when the conversion is not exact.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Status};
+  ///
+  /// let (value, _) = bid128_from_string("1.20E+3");
+  /// assert_eq!(Ok(1200), value.to_i64_exact());
+  ///
+  /// let (value, _) = bid128_from_string("0.5");
+  /// assert_eq!(Err(Status::INEXACT), value.to_i64_exact());
+  /// ```
+  pub fn to_i64_exact(&self) -> Result<i64, Status> {
+    exact(self.to_i64_rnd(Rounding::ToZero))
+  }
+
+  /// Converts this decimal into [u64], fails with status flags when the conversion is not exact.
+  pub fn to_u64_exact(&self) -> Result<u64, Status> {
+    exact(self.to_u64_rnd(Rounding::ToZero))
+  }
+
+  /// Converts this decimal into [i128], fails with status flags when the conversion is not exact.
+  pub fn to_i128_exact(&self) -> Result<i128, Status> {
+    exact(self.to_i128_rnd(Rounding::ToZero))
+  }
+
+  /// Rounds this decimal to an integer, returns the sign, the absolute value and status flags,
+  /// `None` when the decimal is not finite or the absolute value does not fit in [u128].
+  fn round_to_integer(&self, rnd: Rounding) -> Option<(bool, u128, Status)> {
+    let Value::Finite(sign, coefficient, exponent, _) = self.unpack() else {
+      return None;
+    };
+    if coefficient == 0 {
+      return Some((sign, 0, Status::NONE));
+    }
+    if exponent >= 0 {
+      let value = 10_u128.checked_pow(exponent as u32)?.checked_mul(coefficient)?;
+      return Some((sign, value, Status::NONE));
+    }
+    let (value, status) = round_to_exponent(sign, coefficient, exponent, 0, rnd)?;
+    Some((sign, value, status))
+  }
+
+  /// Converts an integer into the nearest decimal with zero exponent when possible,
+  /// integers with more than 34 digits are rounded.
+  fn from_integer(sign: bool, mut value: u128, rnd: Rounding) -> (Bid128, Status) {
+    let mut exponent = 0;
+    let mut status = Status::NONE;
+    let digits = value.checked_ilog10().unwrap_or(0) + 1;
+    if digits > MAX_DIGITS {
+      exponent = (digits - MAX_DIGITS) as i32;
+      // rounding to a greater exponent always gives a coefficient
+      (value, status) = round_to_exponent(sign, value, 0, exponent, rnd).unwrap();
+      // rounding up may produce one digit more than allowed
+      if value == 10_u128.pow(MAX_DIGITS) {
+        value /= 10;
+        exponent += 1;
+      }
+    }
+    let (bid, _) = bid128_from_value(Value::Finite(sign, value, exponent, Status::NONE));
+    (bid, status)
+  }
+}
+
+impl From<i64> for Bid128 {
+  /// Converts [i64] into [Bid128] exactly, with zero exponent.
+  fn from(value: i64) -> Self {
+    Bid128::from_integer(value < 0, value.unsigned_abs() as u128, Rounding::ToNearest).0
+  }
+}
+
+impl From<u128> for Bid128 {
+  /// Converts [u128] into [Bid128] with zero exponent,
+  /// values with more than 34 digits are rounded to nearest, ties to even.
+  fn from(value: u128) -> Self {
+    Bid128::from_integer(false, value, Rounding::ToNearest).0
+  }
+}
+
+/// Returns the converted value when the status flags are empty.
+fn exact<T>((value, status): (T, Status)) -> Result<T, Status> {
+  if status.is_empty() {
+    Ok(value)
+  } else {
+    Err(status)
+  }
+}
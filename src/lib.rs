@@ -29,12 +29,41 @@
 extern crate alloc;
 extern crate core;
 
+mod arithmetic;
+mod bid;
 mod bid128;
+mod bid32;
+mod bid64;
+mod bignum;
+mod binary;
+mod compare;
+mod dpd128;
+mod error;
+mod formatter;
+mod integer;
 mod number;
+mod options;
+mod powers;
+mod quantize;
 mod recognizer;
+mod status;
 #[cfg(test)]
 mod tests;
 
-pub use bid128::{bid128_from_string, Bid128};
-pub use number::{number_from_string, Number};
+pub use bid128::{
+  bid128_from_bytes, bid128_from_bytes_rnd, bid128_from_string, bid128_from_string_prefix,
+  bid128_from_string_prefix_rnd, bid128_from_string_rnd, bid128_from_string_with_options, bid128_to_string,
+  try_bid128_from_string, try_bid128_from_string_rnd, Bid128, Class,
+};
+pub use bid32::{bid32_from_string, bid32_from_string_rnd, Bid32};
+pub use bid64::{bid64_from_string, bid64_from_string_rnd, Bid64};
+pub use binary::{f32_from_string_rnd, f64_from_string_rnd};
+pub use dpd128::Dpd128;
+pub use error::{FromStrError, ParseError, ParseErrorKind, RangePolicy, TryFromFloatError};
+pub use number::{
+  number_from_bytes, number_from_string, number_from_string_prefix, number_from_string_with_options,
+  try_number_from_string, Number,
+};
+pub use options::{ParseOptions, WhitespacePolicy};
 pub use recognizer::Rounding;
+pub use status::{Status, StatusIter};
@@ -24,8 +24,15 @@
 
 //! # Number parser
 
-use crate::recognizer::{recognize, Value};
-use crate::Rounding;
+use crate::bid128::MAX_PAYLOAD;
+use crate::error::{FromStrError, ParseError, RangePolicy};
+use crate::formatter::{pad_scientific, write_engineering};
+use crate::options::ParseOptions;
+use crate::recognizer::{recognize, Mode, Value};
+use crate::{Rounding, Status};
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
 
 /// Parsed number.
 #[derive(Eq, PartialEq)]
@@ -56,9 +63,114 @@ pub enum Number {
     /// Flag indicating if this is a signalling NaN,
     /// `true` signaling, `false` quiet.
     bool,
+    /// Higher 64-bits of the diagnostic information (payload).
+    u64,
+    /// Lower 64-bits of the diagnostic information (payload).
+    u64,
   ),
 }
 
+impl Number {
+  /// Converts this number into text using the to-engineering-string rules,
+  /// the exponent (if present) is always a multiple of three.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::Number;
+  ///
+  /// assert_eq!("123.45678E+6", Number::Finite(false, 0, 12345678, 1).to_engineering_string());
+  /// assert_eq!("-Infinity", Number::Infinite(true).to_engineering_string());
+  /// ```
+  pub fn to_engineering_string(&self) -> String {
+    let mut s = String::new();
+    let _ = write_engineering(&mut s, &self.value());
+    s
+  }
+
+  /// Returns the value represented by this number.
+  fn value(&self) -> Value {
+    match self {
+      Number::Finite(sign, hi, lo, exponent) => {
+        Value::Finite(*sign, (*hi as u128) << 64 | *lo as u128, *exponent, Status::NONE)
+      }
+      Number::Infinite(sign) => Value::Infinity(*sign),
+      Number::NaN(sign, signaling, hi, lo) => Value::NaN(*sign, *signaling, (*hi as u128) << 64 | *lo as u128),
+    }
+  }
+}
+
+impl Number {
+  /// Parses a number properties from text in scientific notation,
+  /// reports syntax errors and exceptional results rejected by the policy.
+  ///
+  /// The exponent of a number is not limited, so only rounding
+  /// to 34 significant digits may be rejected by the policy.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{FromStrError, Number, RangePolicy};
+  ///
+  /// let input = "12345678901234567890123456789012345";
+  /// assert!(Number::from_str_with_policy(input, RangePolicy::default()).is_ok());
+  /// assert!(matches!(Number::from_str_with_policy(input, RangePolicy::EXACT), Err(FromStrError::Inexact)));
+  /// ```
+  pub fn from_str_with_policy(input: &str, policy: RangePolicy) -> Result<Number, FromStrError> {
+    match recognize(
+      input.as_bytes(),
+      34,
+      Rounding::ToNearest,
+      Mode::Strict,
+      &ParseOptions::new(),
+    )
+    .0
+    {
+      Value::Invalid(_, err) => Err(err.into()),
+      value => {
+        if let Value::Finite(_, _, _, status) = value {
+          policy.check(status)?;
+        }
+        Ok(number_from_value(value))
+      }
+    }
+  }
+}
+
+impl FromStr for Number {
+  type Err = FromStrError;
+
+  /// Parses [Number] from text using the default [RangePolicy].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::Number;
+  ///
+  /// assert!("1.5e3".parse::<Number>() == Ok(Number::Finite(false, 0, 15, 2)));
+  /// assert!("1.5e".parse::<Number>().is_err());
+  /// ```
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Number::from_str_with_policy(s, RangePolicy::default())
+  }
+}
+
+impl TryFrom<&str> for Number {
+  type Error = FromStrError;
+
+  /// Converts text into [Number] using the default [RangePolicy].
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl fmt::Display for Number {
+  /// Formats the number using the to-scientific-string rules, padded to the requested width.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    pad_scientific(f, &self.value())
+  }
+}
+
 /// Parses a number properties from text in scientific notation.
 ///
 /// # Examples
@@ -102,7 +214,18 @@ pub enum Number {
 ///
 /// let result = number_from_string("NaN");
 /// match result {
-///   Number::NaN(false, false) => {}
+///   Number::NaN(false, false, 0, 0) => {}
+///   _ => panic!()
+/// }
+/// ```
+///
+/// Input text represents quiet not-a-number with diagnostic information.
+/// ```
+/// use scidec::{Number, number_from_string};
+///
+/// let result = number_from_string("NaN123");
+/// match result {
+///   Number::NaN(false, false, 0, 123) => {}
 ///   _ => panic!()
 /// }
 /// ```
@@ -113,21 +236,129 @@ pub enum Number {
 ///
 /// let result = number_from_string("SNaN");
 /// match result {
-///   Number::NaN(false, true) => {}
+///   Number::NaN(false, true, 0, 0) => {}
 ///   _ => panic!()
 /// }
 /// ```
 pub fn number_from_string(input: &str) -> Number {
-  match recognize(input, 34, Rounding::ToNearest) {
+  number_from_bytes(input.as_bytes())
+}
+
+/// Parses a number properties from ASCII text in scientific notation.
+///
+/// The input is not required to be valid UTF-8, the result is the same
+/// as for the text parsed by [number_from_string].
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{Number, number_from_bytes};
+///
+/// assert!(number_from_bytes(b"0.00003E-02") == Number::Finite(false, 0, 3, -7));
+/// assert!(number_from_bytes(b"\xfe") == Number::NaN(false, false, 0, 0));
+/// ```
+pub fn number_from_bytes(input: &[u8]) -> Number {
+  number_from_value(recognize(input, 34, Rounding::ToNearest, Mode::Lenient, &ParseOptions::new()).0)
+}
+
+/// Parses a number properties from text in scientific notation,
+/// reports an error when the text is not a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{Number, ParseErrorKind, try_number_from_string};
+///
+/// assert!(try_number_from_string("1234.5678e-2") == Ok(Number::Finite(false, 0, 12345678, -6)));
+/// assert!(try_number_from_string("NaN") == Ok(Number::NaN(false, false, 0, 0)));
+///
+/// let err = try_number_from_string("").err().unwrap();
+/// assert_eq!(ParseErrorKind::Empty, err.kind());
+/// ```
+pub fn try_number_from_string(input: &str) -> Result<Number, ParseError> {
+  match recognize(
+    input.as_bytes(),
+    34,
+    Rounding::ToNearest,
+    Mode::Strict,
+    &ParseOptions::new(),
+  )
+  .0
+  {
+    Value::Invalid(_, err) => Err(err),
+    value => Ok(number_from_value(value)),
+  }
+}
+
+/// Parses a number properties from text in scientific notation, with parsing options,
+/// reports an error when the text is not a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{Number, ParseErrorKind, ParseOptions, number_from_string_with_options};
+///
+/// let options = ParseOptions::new().with_group_separator('\'').with_strict_grouping(true);
+/// assert!(number_from_string_with_options("1'234.56", &options) == Ok(Number::Finite(false, 0, 123456, -2)));
+///
+/// let err = number_from_string_with_options("1'23.56", &options).err().unwrap();
+/// assert_eq!(ParseErrorKind::InvalidGrouping, err.kind());
+/// assert_eq!(4, err.offset());
+/// ```
+pub fn number_from_string_with_options(input: &str, options: &ParseOptions) -> Result<Number, ParseError> {
+  match recognize(input.as_bytes(), 34, Rounding::ToNearest, options.mode(), options).0 {
+    Value::Invalid(_, err) => Err(err),
+    value => Ok(number_from_value(value)),
+  }
+}
+
+/// Parses a number properties from the longest prefix of the text being a number
+/// in scientific notation, returns the number of bytes consumed from the text.
+///
+/// Reports an error when the text does not start with a valid number.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{Number, number_from_string_prefix};
+///
+/// assert!(number_from_string_prefix("-0.25;") == Ok((Number::Finite(true, 0, 25, -2), 5)));
+/// assert!(number_from_string_prefix("infinite") == Ok((Number::Infinite(false), 3)));
+/// assert!(number_from_string_prefix("NaN12)") == Ok((Number::NaN(false, false, 0, 12), 5)));
+/// ```
+pub fn number_from_string_prefix(input: &str) -> Result<(Number, usize), ParseError> {
+  match recognize(
+    input.as_bytes(),
+    34,
+    Rounding::ToNearest,
+    Mode::Prefix,
+    &ParseOptions::new(),
+  ) {
+    (Value::Invalid(_, err), _) => Err(err),
+    (value, consumed) => Ok((number_from_value(value), consumed)),
+  }
+}
+
+/// Converts the recognized value into a number.
+fn number_from_value(value: Value) -> Number {
+  match value {
     Value::Finite(sign, value, exponent, _status) => Number::Finite(sign, (value >> 64) as u64, value as u64, exponent),
     Value::Infinity(sign) => Number::Infinite(sign),
-    Value::NaN(sign, signaling) => Number::NaN(sign, signaling),
+    Value::NaN(sign, signaling, mut payload) => {
+      // non-canonical payloads are replaced with zero
+      if payload > MAX_PAYLOAD {
+        payload = 0;
+      }
+      Number::NaN(sign, signaling, (payload >> 64) as u64, payload as u64)
+    }
+    Value::Invalid(sign, _) => Number::NaN(sign, false, 0, 0),
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use alloc::string::ToString;
 
   #[test]
   fn test_eq() {
@@ -135,8 +366,36 @@ mod tests {
     assert!((Number::Finite(false, 0, 0, 0) != Number::Infinite(false)));
     assert!((Number::Infinite(true) != Number::Infinite(false)));
     assert!((Number::Infinite(true) == Number::Infinite(true)));
-    assert!((Number::NaN(true, true) != Number::NaN(false, false)));
-    assert!((Number::NaN(false, false) == Number::NaN(false, false)));
-    Number::Infinite(false).assert_receiver_is_total_eq();
+    assert!((Number::NaN(true, true, 0, 0) != Number::NaN(false, false, 0, 0)));
+    assert!((Number::NaN(false, false, 0, 0) == Number::NaN(false, false, 0, 0)));
+    assert!((Number::NaN(false, false, 0, 1) != Number::NaN(false, false, 0, 0)));
+    assert_total_eq(&Number::Infinite(false));
   }
+
+  #[test]
+  fn test_display() {
+    assert_eq!("12.345678", number_from_string("1234.5678e-2").to_string());
+    assert_eq!("1.2E+7", number_from_string("12e6").to_string());
+    assert_eq!("-Infinity", number_from_string("-inf").to_string());
+    assert_eq!("sNaN", number_from_string("snan").to_string());
+    assert_eq!("-NaN12", number_from_string("-nan012").to_string());
+  }
+
+  #[test]
+  fn test_payload() {
+    assert!((Number::NaN(false, false, 0, 123) == number_from_string("NaN123")));
+    assert!((Number::NaN(true, true, 0, 456) == number_from_string("-sNaN456")));
+    assert!((Number::NaN(true, false, 0, 0) == number_from_string("-sNaN456x")));
+    assert!((Number::NaN(false, false, 0x36, 0x35c9adc5de9fffff) == number_from_string("nan999999999999999999999")));
+    assert!((Number::NaN(false, false, 0, 0) == number_from_string("nan1000000000000000000000000000000000")));
+  }
+
+  #[test]
+  fn test_engineering() {
+    assert_eq!("12E+6", number_from_string("12e6").to_engineering_string());
+    assert_eq!("0.0E+3", number_from_string("0e2").to_engineering_string());
+    assert_eq!("-NaN", number_from_string("-nan").to_engineering_string());
+  }
+
+  fn assert_total_eq<T: Eq>(_: &T) {}
 }
@@ -0,0 +1,240 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Options for parsing numbers
+
+use crate::recognizer::Mode;
+
+/// Policy of accepting whitespace surrounding the number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WhitespacePolicy {
+  /// When `true`, whitespace before the number is skipped.
+  pub allow_leading: bool,
+  /// When `true`, whitespace after the number is skipped.
+  pub allow_trailing: bool,
+}
+
+impl WhitespacePolicy {
+  /// Policy rejecting any whitespace.
+  pub const REJECT: WhitespacePolicy = WhitespacePolicy {
+    allow_leading: false,
+    allow_trailing: false,
+  };
+
+  /// Policy accepting whitespace only before the number.
+  pub const LEADING: WhitespacePolicy = WhitespacePolicy {
+    allow_leading: true,
+    allow_trailing: false,
+  };
+
+  /// Policy accepting whitespace before and after the number.
+  pub const TRIM: WhitespacePolicy = WhitespacePolicy {
+    allow_leading: true,
+    allow_trailing: true,
+  };
+}
+
+impl Default for WhitespacePolicy {
+  /// Default policy accepts whitespace only before the number.
+  fn default() -> Self {
+    Self::LEADING
+  }
+}
+
+/// Options controlling how the text is recognized as a number.
+///
+/// Options are created with [ParseOptions::new] and adjusted using `with_*` methods.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{number_from_string_with_options, Number, ParseOptions, WhitespacePolicy};
+///
+/// let options = ParseOptions::new().with_group_separator(',').with_strict_grouping(true);
+/// assert!(number_from_string_with_options("1,234,567.89", &options) == Ok(Number::Finite(false, 0, 123456789, -2)));
+/// assert!(number_from_string_with_options("12,34,567.89", &options).is_err());
+///
+/// let options = ParseOptions::new()
+///   .with_decimal_mark(',')
+///   .with_group_separator('.')
+///   .with_strict_grouping(true);
+/// assert!(number_from_string_with_options("1.234,56", &options) == Ok(Number::Finite(false, 0, 123456, -2)));
+/// assert!(number_from_string_with_options("1234.56", &options).is_err());
+///
+/// let options = ParseOptions::new()
+///   .with_whitespace(WhitespacePolicy::TRIM)
+///   .with_leading_plus(false)
+///   .with_bare_point(false)
+///   .with_infinity(false)
+///   .with_nan(false);
+/// assert!(number_from_string_with_options(" 0.5 ", &options) == Ok(Number::Finite(false, 0, 5, -1)));
+/// assert!(number_from_string_with_options("+1", &options).is_err());
+/// assert!(number_from_string_with_options(".5", &options).is_err());
+/// assert!(number_from_string_with_options("Infinity", &options).is_err());
+/// assert!(number_from_string_with_options("NaN", &options).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+  /// Character separating the integral part from the fractional part, like `'.'`, `','`
+  /// or Arabic decimal separator `'\u{66B}'`.
+  ///
+  /// When other than `'.'`, the full stop is not accepted as the decimal point.
+  /// Digits, signs, letters and whitespace are rejected as [ParseErrorKind::InvalidOptions](crate::ParseErrorKind::InvalidOptions).
+  pub decimal_mark: char,
+  /// Character separating groups of digits, like `'_'`, `','`, `'\''` or thin space `'\u{2009}'`.
+  ///
+  /// The separator is recognized only between two digits of the coefficient.
+  /// Separators equal to digits, signs, the decimal mark or the exponent marker
+  /// are rejected as [ParseErrorKind::InvalidOptions](crate::ParseErrorKind::InvalidOptions).
+  pub group_separator: Option<char>,
+  /// Flag indicating if the positions of group separators are validated, if `true` then validated.
+  ///
+  /// Groups must have three digits, except the first group of the integral part
+  /// and the last group of the fractional part, having from one to three digits.
+  pub strict_grouping: bool,
+  /// Policy of accepting whitespace surrounding the number.
+  pub whitespace: WhitespacePolicy,
+  /// When `true`, the number may start with a plus sign.
+  pub allow_leading_plus: bool,
+  /// When `true`, the decimal mark does not have to be surrounded by digits, like in `.5` or `5.`.
+  pub allow_bare_point: bool,
+  /// When `true`, keywords `inf` and `infinity` are accepted.
+  pub allow_infinity: bool,
+  /// When `true`, keywords `nan` and `snan` are accepted.
+  pub allow_nan: bool,
+  /// When `true`, keywords must be spelled exactly like `Inf`, `Infinity`, `NaN` and `sNaN`.
+  pub case_sensitive: bool,
+  /// When `true`, the text following the longest prefix being a number is ignored.
+  pub ignore_trailing: bool,
+}
+
+impl ParseOptions {
+  /// Creates options accepting numbers with the decimal point and without group separators,
+  /// whitespace is accepted only before the number, keywords are case-insensitive.
+  pub const fn new() -> Self {
+    Self {
+      decimal_mark: '.',
+      group_separator: None,
+      strict_grouping: false,
+      whitespace: WhitespacePolicy::LEADING,
+      allow_leading_plus: true,
+      allow_bare_point: true,
+      allow_infinity: true,
+      allow_nan: true,
+      case_sensitive: false,
+      ignore_trailing: false,
+    }
+  }
+
+  /// Sets the decimal mark.
+  pub const fn with_decimal_mark(mut self, decimal_mark: char) -> Self {
+    self.decimal_mark = decimal_mark;
+    self
+  }
+
+  /// Sets the group separator.
+  pub const fn with_group_separator(mut self, group_separator: char) -> Self {
+    self.group_separator = Some(group_separator);
+    self
+  }
+
+  /// Sets the flag indicating if the positions of group separators are validated.
+  pub const fn with_strict_grouping(mut self, strict_grouping: bool) -> Self {
+    self.strict_grouping = strict_grouping;
+    self
+  }
+
+  /// Sets the policy of accepting whitespace surrounding the number.
+  pub const fn with_whitespace(mut self, whitespace: WhitespacePolicy) -> Self {
+    self.whitespace = whitespace;
+    self
+  }
+
+  /// Sets the flag indicating if the number may start with a plus sign.
+  pub const fn with_leading_plus(mut self, allow_leading_plus: bool) -> Self {
+    self.allow_leading_plus = allow_leading_plus;
+    self
+  }
+
+  /// Sets the flag indicating if the decimal mark does not have to be surrounded by digits.
+  pub const fn with_bare_point(mut self, allow_bare_point: bool) -> Self {
+    self.allow_bare_point = allow_bare_point;
+    self
+  }
+
+  /// Sets the flag indicating if keywords `inf` and `infinity` are accepted.
+  pub const fn with_infinity(mut self, allow_infinity: bool) -> Self {
+    self.allow_infinity = allow_infinity;
+    self
+  }
+
+  /// Sets the flag indicating if keywords `nan` and `snan` are accepted.
+  pub const fn with_nan(mut self, allow_nan: bool) -> Self {
+    self.allow_nan = allow_nan;
+    self
+  }
+
+  /// Sets the flag indicating if keywords are case-sensitive.
+  pub const fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+    self.case_sensitive = case_sensitive;
+    self
+  }
+
+  /// Sets the flag indicating if the text following the number is ignored.
+  pub const fn with_ignore_trailing(mut self, ignore_trailing: bool) -> Self {
+    self.ignore_trailing = ignore_trailing;
+    self
+  }
+
+  /// Returns `true` when the decimal mark and the group separator differ from each other
+  /// and from characters having other meaning in the number.
+  ///
+  /// Letters of keywords and whitespace are allowed only as the group separator,
+  /// which is never placed at the beginning of the number.
+  pub(crate) fn is_valid(&self) -> bool {
+    let reserved = |ch: char| ch.is_ascii_digit() || matches!(ch, '+' | '-' | 'e' | 'E');
+    !reserved(self.decimal_mark)
+      && !self.decimal_mark.is_ascii_alphabetic()
+      && !self.decimal_mark.is_whitespace()
+      && self
+        .group_separator
+        .is_none_or(|separator| separator != self.decimal_mark && !reserved(separator))
+  }
+
+  /// Returns the mode of recognizing the text with these options.
+  pub(crate) fn mode(&self) -> Mode {
+    if self.ignore_trailing {
+      Mode::Prefix
+    } else {
+      Mode::Strict
+    }
+  }
+}
+
+impl Default for ParseOptions {
+  /// Creates default options, see [ParseOptions::new].
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,230 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Quantization of decimals
+
+use crate::arithmetic::{infinity, invalid, propagate_nan};
+use crate::bid128::{bid128_from_value, Bid128, BID128_BIAS, MAX_COEFFICIENT, MAX_EXPONENT};
+use crate::recognizer::{Rounding, Value};
+use crate::Status;
+
+impl Bid128 {
+  /// Returns this decimal rounded to the exponent of other decimal, with rounding mode.
+  ///
+  /// The result has exactly the exponent of other decimal, its coefficient is rounded when digits are dropped.
+  /// When the coefficient does not fit in 34 digits, or only one of the decimals is an infinity,
+  /// the result is invalid. Quantizing infinity by infinity returns this infinity.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (cents, _) = bid128_from_string("0.01");
+  /// let (price, _) = bid128_from_string("12.345");
+  /// let (rounded, status) = price.quantize(&cents, Rounding::ToNearest);
+  /// assert_eq!("12.34", rounded.to_string());
+  /// assert!(status.is_inexact());
+  ///
+  /// let (price, _) = bid128_from_string("12");
+  /// let (rounded, status) = price.quantize(&cents, Rounding::ToNearest);
+  /// assert_eq!("12.00", rounded.to_string());
+  /// assert!(status.is_empty());
+  /// ```
+  pub fn quantize(&self, other: &Bid128, rnd: Rounding) -> (Bid128, Status) {
+    let (a, b) = (self.unpack(), other.unpack());
+    if let Some(result) = propagate_nan(&[&a, &b]) {
+      return result;
+    }
+    match (a, b) {
+      (Value::Infinity(sign), Value::Infinity(_)) => infinity(sign),
+      (Value::Finite(sign, coefficient, exponent, _), Value::Finite(_, _, target, _)) => {
+        rescale_finite(sign, coefficient, exponent, target, rnd)
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns this decimal rounded to the specified exponent, with rounding mode.
+  ///
+  /// Works like [Bid128::quantize] with the exponent given explicitly,
+  /// exponents out of the range of encodable exponents are invalid, as well as infinities.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (amount, _) = bid128_from_string("-2.675");
+  /// let (rounded, status) = amount.rescale(-2, Rounding::TiesAway);
+  /// assert_eq!("-2.68", rounded.to_string());
+  /// assert!(status.is_inexact());
+  /// ```
+  pub fn rescale(&self, exponent: i32, rnd: Rounding) -> (Bid128, Status) {
+    let a = self.unpack();
+    if let Some(result) = propagate_nan(&[&a]) {
+      return result;
+    }
+    match a {
+      Value::Finite(sign, coefficient, current, _) if (-BID128_BIAS..=MAX_EXPONENT).contains(&exponent) => {
+        rescale_finite(sign, coefficient, current, exponent, rnd)
+      }
+      _ => invalid(),
+    }
+  }
+
+  /// Returns this decimal rounded to an integral value, with rounding mode.
+  ///
+  /// Decimals with fractional digits are rounded to the exponent zero, other decimals
+  /// are returned unchanged. Only the invalid flag is reported, for signaling NaNs.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("-2.5");
+  /// let (result, status) = a.round_to_integral(Rounding::ToNearest);
+  /// assert_eq!("-2", result.to_string());
+  /// assert!(status.is_empty());
+  /// let (result, _) = a.round_to_integral(Rounding::TiesAway);
+  /// assert_eq!("-3", result.to_string());
+  /// ```
+  pub fn round_to_integral(&self, rnd: Rounding) -> (Bid128, Status) {
+    let (result, status) = self.round_to_integral_exact(rnd);
+    (result, status & Status::INVALID)
+  }
+
+  /// Returns this decimal rounded to an integral value, with rounding mode,
+  /// reports inexact result when the value was changed by rounding.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::{bid128_from_string, Rounding};
+  ///
+  /// let (a, _) = bid128_from_string("7.25");
+  /// let (result, status) = a.round_to_integral_exact(Rounding::Up);
+  /// assert_eq!("8", result.to_string());
+  /// assert!(status.is_inexact());
+  /// ```
+  pub fn round_to_integral_exact(&self, rnd: Rounding) -> (Bid128, Status) {
+    let a = self.unpack();
+    if let Some(result) = propagate_nan(&[&a]) {
+      return result;
+    }
+    match a {
+      Value::Finite(sign, coefficient, exponent, _) if exponent < 0 => {
+        rescale_finite(sign, coefficient, exponent, 0, rnd)
+      }
+      _ => bid128_from_value(a),
+    }
+  }
+
+  /// Returns the largest integral value less than or equal to this decimal.
+  pub fn floor(&self) -> Bid128 {
+    self.round_to_integral(Rounding::Down).0
+  }
+
+  /// Returns the smallest integral value greater than or equal to this decimal.
+  pub fn ceil(&self) -> Bid128 {
+    self.round_to_integral(Rounding::Up).0
+  }
+
+  /// Returns the integral part of this decimal, rounding toward zero.
+  pub fn trunc(&self) -> Bid128 {
+    self.round_to_integral(Rounding::ToZero).0
+  }
+
+  /// Returns the nearest integral value, rounding halfway cases away from zero.
+  pub fn round(&self) -> Bid128 {
+    self.round_to_integral(Rounding::TiesAway).0
+  }
+
+  /// Returns `true` when this decimal and other decimal have the same exponent.
+  ///
+  /// All NaNs have the same quantum, as well as all infinities.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use scidec::bid128_from_string;
+  ///
+  /// let (a, _) = bid128_from_string("2.17");
+  /// let (b, _) = bid128_from_string("0.01");
+  /// let (c, _) = bid128_from_string("0.1");
+  /// assert!(a.same_quantum(&b));
+  /// assert!(!a.same_quantum(&c));
+  /// ```
+  pub fn same_quantum(&self, other: &Bid128) -> bool {
+    match (self.unpack(), other.unpack()) {
+      (Value::NaN(..), Value::NaN(..)) | (Value::Infinity(_), Value::Infinity(_)) => true,
+      (Value::Finite(_, _, exponent_a, _), Value::Finite(_, _, exponent_b, _)) => exponent_a == exponent_b,
+      _ => false,
+    }
+  }
+}
+
+/// Rounds the finite number to the target exponent and encodes it,
+/// the result is invalid when the coefficient does not fit in 34 digits.
+fn rescale_finite(sign: bool, coefficient: u128, exponent: i32, target: i32, rnd: Rounding) -> (Bid128, Status) {
+  match round_to_exponent(sign, coefficient, exponent, target, rnd) {
+    Some((coefficient, status)) => (
+      bid128_from_value(Value::Finite(sign, coefficient, target, Status::NONE)).0,
+      status,
+    ),
+    None => invalid(),
+  }
+}
+
+/// Rounds the coefficient of the finite number to the target exponent, returns the rounded coefficient
+/// and status flags, `None` when the coefficient does not fit in 34 digits.
+pub(crate) fn round_to_exponent(
+  sign: bool,
+  coefficient: u128,
+  exponent: i32,
+  target: i32,
+  rnd: Rounding,
+) -> Option<(u128, Status)> {
+  if coefficient == 0 {
+    return Some((0, Status::NONE));
+  }
+  if target <= exponent {
+    let value = 10_u128
+      .checked_pow((exponent - target) as u32)?
+      .checked_mul(coefficient)
+      .filter(|value| *value <= MAX_COEFFICIENT)?;
+    return Some((value, Status::NONE));
+  }
+  // coefficients have at most 34 digits, so dividing by a larger power of ten always gives zero
+  let (quotient, remainder, half) = match 10_u128.checked_pow((target - exponent) as u32) {
+    Some(divisor) => (coefficient / divisor, coefficient % divisor, divisor / 2),
+    None => (0, coefficient, u128::MAX),
+  };
+  if remainder == 0 {
+    return Some((quotient, Status::NONE));
+  }
+  let carry = rnd.carry(sign, quotient & 1 == 1, remainder.cmp(&half), true);
+  Some((quotient + carry as u128, Status::INEXACT))
+}
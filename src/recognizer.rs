@@ -24,13 +24,13 @@
 
 //! Implementation of the recognizer for scientific `E` notation.
 
-type Flags = u32;
-
-pub const FLAG_OVERFLOW: Flags = 0x08;
-pub const FLAG_UNDERFLOW: Flags = 0x10;
-pub const FLAG_INEXACT: Flags = 0x20;
+use crate::error::{ParseError, ParseErrorKind};
+use crate::options::ParseOptions;
+use crate::Status;
+use core::cmp::Ordering;
 
 /// Rounding modes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(i32)]
 pub enum Rounding {
   ToNearest = 0x00000,
@@ -54,7 +54,35 @@ impl From<i32> for Rounding {
   }
 }
 
+impl Rounding {
+  /// Returns `true` when the magnitude truncated toward zero has to be incremented by one unit.
+  ///
+  /// `odd` is the parity of the truncated magnitude, `half` is the ordering of the dropped part
+  /// compared to the half of the unit, `sticky` is `true` when the dropped part is nonzero.
+  pub(crate) fn carry(self, sign: bool, odd: bool, half: Ordering, sticky: bool) -> bool {
+    match self {
+      Rounding::ToNearest => half == Ordering::Greater || (half == Ordering::Equal && odd),
+      Rounding::Down => sign && sticky,
+      Rounding::Up => !sign && sticky,
+      Rounding::ToZero => false,
+      Rounding::TiesAway => half != Ordering::Less,
+    }
+  }
+}
+
+/// Modes of recognizing the input text.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+  /// Incomplete input and trailing characters after the exponent are accepted.
+  Lenient,
+  /// Only a complete number spanning the whole input text is accepted.
+  Strict,
+  /// The longest prefix of the input text being a complete number is accepted.
+  Prefix,
+}
+
 /// States of the finite state machine used to parse the input text.
+#[derive(PartialEq, Eq)]
 enum State {
   BeginNumber,
   LeadingZerosBefore,
@@ -70,9 +98,23 @@ enum State {
   Inf6i,
   Inf7t,
   Inf8y,
+  InfEnd,
   Nan1n,
   Nan2a,
   Nan3n,
+  NanPayload,
+}
+
+impl State {
+  /// Returns the part of the coefficient being recognized in this state,
+  /// `1` for the integral part, `2` for the fractional part and `0` otherwise.
+  fn part(&self) -> u8 {
+    match self {
+      State::LeadingZerosBefore | State::DigitsBefore => 1,
+      State::DigitsAfter => 2,
+      _ => 0,
+    }
+  }
 }
 
 /// Parsed number.
@@ -86,7 +128,7 @@ pub enum Value {
     /// Exponent.
     i32,
     /// Exception status flags.
-    Flags,
+    Status,
   ),
   /// Variant representing an infinity.
   Infinity(
@@ -99,270 +141,490 @@ pub enum Value {
     bool,
     /// Flag indicating if this is a signalling NaN, if `true` then signaling.
     bool,
+    /// Diagnostic information (payload), saturated when too large.
+    u128,
+  ),
+  /// Variant representing a text that is not a valid number.
+  Invalid(
+    /// Flag indicating if the text starts with a minus sign, if `true` then signed.
+    bool,
+    /// Reason of rejecting the text.
+    ParseError,
   ),
 }
 
+/// Number scanned from the input text, before rounding.
+///
+/// Digits are kept on the stack, parsing never allocates.
+/// At most `N` significant digits are stored, the remaining digits are only flagged.
+pub enum Scanned<const N: usize> {
+  /// Finite number with all its significant digits.
+  Finite(Digits<N>),
+  /// Infinity, NaN or a text that is not a valid number.
+  Special(Value),
+}
+
+/// Significant digits of a finite number scanned from the input text.
+pub struct Digits<const N: usize> {
+  /// Flag indicating if the number is signed, if `true` then signed.
+  pub sign: bool,
+  /// Value of the leading significant digits, limited to the requested number of digits.
+  pub value: u128,
+  /// Exponent of the value.
+  pub exponent: i32,
+  /// All significant digits, starting with the first nonzero digit.
+  pub buffer: [u8; N],
+  /// Number of significant digits stored in the buffer.
+  pub count: usize,
+  /// Flag indicating if nonzero digits were not included in the value.
+  pub inexact: bool,
+  /// Flag indicating if nonzero digits did not fit in the buffer.
+  pub truncated: bool,
+}
+
 /// Maximum number of recognized digits.
 const MAX_STRING_DIGITS: usize = 100;
 
 macro_rules! update_value {
-  ($value:expr, $ch:expr, $digits:expr, $max_digits: expr, $digits_total:expr, $buffer:expr, $inexact:expr) => {{
-    let b = ($ch as u8) - b'0';
+  ($value:expr, $ch:expr, $digits:expr, $max_digits: expr, $digits_total:expr, $buffer:expr, $inexact:expr, $truncated:expr) => {{
+    let b = $ch - b'0';
     if $digits < $max_digits {
       $value = $value * 10 + b as u128;
       if $value > 0 {
         $digits += 1;
       }
-    } else {
-      $inexact = b > 0;
+    } else if b > 0 {
+      $inexact = true;
     }
-    if $value > 0 && $digits_total < MAX_STRING_DIGITS {
-      $buffer[$digits_total] = b;
-      $digits_total += 1;
+    if $value > 0 {
+      if $digits_total < $buffer.len() {
+        $buffer[$digits_total] = b;
+        $digits_total += 1;
+      } else if b > 0 {
+        $truncated = true;
+      }
     }
   }};
 }
 
 macro_rules! update_exponent {
   ($v:expr, $c:expr) => {{
-    $v = $v.saturating_mul(10).saturating_add((($c as u8) - b'0') as i32);
+    $v = $v.saturating_mul(10).saturating_add(($c - b'0') as i32);
   }};
 }
 
 /// Recognizes a number from scientific notation.
-pub fn recognize(input: &str, max_digits: usize, rnd: Rounding) -> Value {
+///
+/// The input text is processed byte by byte, bytes outside ASCII are never a part of a number.
+///
+/// In [Mode::Strict], any text that is not a complete number is reported as [Value::Invalid].
+/// In [Mode::Prefix], recognizing stops at the first character that can not extend a complete number.
+/// In [Mode::Lenient], incomplete input and trailing characters after the exponent are accepted.
+///
+/// Group separators allowed by options are skipped when placed between two digits of the coefficient,
+/// the decimal mark configured in options replaces the decimal point.
+///
+/// Returns the recognized value and the number of bytes consumed from the input text.
+pub fn recognize(input: &[u8], max_digits: usize, rnd: Rounding, mode: Mode, options: &ParseOptions) -> (Value, usize) {
+  match scan::<MAX_STRING_DIGITS>(input, max_digits, mode, options) {
+    (Scanned::Finite(digits), consumed) => (digits.round(max_digits, rnd), consumed),
+    (Scanned::Special(value), consumed) => (value, consumed),
+  }
+}
+
+/// Scans a number from scientific notation without rounding its coefficient.
+///
+/// Recognizes the same text as [recognize], finite numbers are returned with all significant digits.
+pub fn scan<const N: usize>(
+  input: &[u8],
+  max_digits: usize,
+  mode: Mode,
+  options: &ParseOptions,
+) -> (Scanned<N>, usize) {
+  if !options.is_valid() {
+    return (
+      Scanned::Special(Value::Invalid(
+        false,
+        ParseError::new(ParseErrorKind::InvalidOptions, 0),
+      )),
+      0,
+    );
+  }
   let mut sign = false;
   let mut signaling = false;
+  // trailing whitespace is skipped when allowed by options
+  let mut input = input;
+  if options.whitespace.allow_trailing {
+    while let [rest @ .., b' ' | b'\t' | b'\n' | b'\r'] = input {
+      input = rest;
+    }
+  }
   if input.is_empty() {
-    return Value::NaN(sign, signaling);
+    return (
+      Scanned::Special(Value::Invalid(sign, ParseError::new(ParseErrorKind::Empty, 0))),
+      0,
+    );
   }
+  let strict = mode != Mode::Lenient;
+  let prefix = mode == Mode::Prefix;
   let mut state = State::BeginNumber;
   let mut exp = 0_i32;
   let mut exp_base = 0_i32;
   let mut exp_sign = 1_i32;
   let mut val = 0_u128;
-  let mut inf = false;
-  let mut nan = false;
+  let mut payload = 0_u128;
   let mut digits = 0_usize;
   let mut digits_total = 0_usize;
+  let mut digits_seen = false;
+  let mut exp_digits_seen = false;
+  let mut fraction_digits_seen = false;
   let mut inexact = false;
-  let mut buffer = [0_u8; MAX_STRING_DIGITS];
+  let mut truncated = false;
+  let mut buffer = [0_u8; N];
+  let mut accepted = 0_usize;
+  let mut separator_buffer = [0_u8; 4];
+  let separator = options
+    .group_separator
+    .map_or(&[][..], |ch| ch.encode_utf8(&mut separator_buffer).as_bytes());
+  let mut decimal_mark_buffer = [0_u8; 4];
+  let decimal_mark = options.decimal_mark.encode_utf8(&mut decimal_mark_buffer).as_bytes();
+  let mut skip = 0_usize;
+  let mut group = 0_usize;
+  let mut grouped = false;
   let last = input.len() - 1;
-  for (position, ch) in input.chars().enumerate() {
+  // keywords are spelled like the formatter does when case-sensitive
+  let letter = |ch: u8, expected: u8| ch == expected || (!options.case_sensitive && ch.eq_ignore_ascii_case(&expected));
+
+  // in prefix mode, the unexpected character ends the number when some prefix was already accepted
+  macro_rules! invalid {
+    ($kind:ident, $offset:expr) => {{
+      if prefix && accepted > 0 {
+        break;
+      }
+      return (
+        Scanned::Special(Value::Invalid(sign, ParseError::new(ParseErrorKind::$kind, $offset))),
+        $offset,
+      );
+    }};
+  }
+
+  // misplaced group separators are reported even in prefix mode
+  macro_rules! check_grouping {
+    ($valid:expr, $offset:expr) => {{
+      if options.strict_grouping && !($valid) {
+        return (
+          Scanned::Special(Value::Invalid(
+            sign,
+            ParseError::new(ParseErrorKind::InvalidGrouping, $offset),
+          )),
+          $offset,
+        );
+      }
+    }};
+  }
+
+  for (position, &byte) in input.iter().enumerate() {
+    // skip the remaining bytes of a multibyte group separator or decimal mark
+    if skip > 0 {
+      skip -= 1;
+      continue;
+    }
+    let part = state.part();
+    // group separator is recognized only between two digits of the coefficient
+    if part > 0
+      && !separator.is_empty()
+      && input[position..].starts_with(separator)
+      && input[position - 1].is_ascii_digit()
+      && input.get(position + separator.len()).is_some_and(u8::is_ascii_digit)
+    {
+      check_grouping!(if part == 1 && !grouped { group <= 3 } else { group == 3 }, position);
+      grouped = true;
+      group = 0;
+      skip = separator.len() - 1;
+      continue;
+    }
+    // decimal mark is recognized as the decimal point, the decimal point itself becomes an unexpected character
+    let (ch, width) = if input[position..].starts_with(decimal_mark) {
+      (b'.', decimal_mark.len())
+    } else if byte == b'.' {
+      (0, 1)
+    } else {
+      (byte, 1)
+    };
+    skip = width - 1;
+    // in strict mode incomplete input is reported after processing the whole text
+    let more = strict || position + width <= last;
     match state {
       State::BeginNumber => match ch {
-        ' ' | '\t' | '\n' | '\r' => {}
-        '-' => {
+        b' ' | b'\t' | b'\n' | b'\r' if options.whitespace.allow_leading => {}
+        b'-' => {
           sign = true;
           state = State::LeadingZerosBefore;
         }
-        '+' | '0' => state = State::LeadingZerosBefore,
-        '1'..='9' => {
-          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact);
+        b'+' if options.allow_leading_plus => state = State::LeadingZerosBefore,
+        b'0' => {
+          digits_seen = true;
+          state = State::LeadingZerosBefore;
+        }
+        b'1'..=b'9' => {
+          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact, truncated);
+          digits_seen = true;
           state = State::DigitsBefore;
         }
-        '.' if position < last => state = State::DigitsAfter,
-        '.' if position == last => break,
-        'i' | 'I' if position < last => state = State::Inf2n,
-        'n' | 'N' if position < last => state = State::Nan2a,
-        's' | 'S' if position < last => {
+        b'.' if more && options.allow_bare_point => state = State::DigitsAfter,
+        b'.' if !more => break,
+        c if letter(c, b'I') && options.allow_infinity && more => state = State::Inf2n,
+        c if letter(c, b'N') && options.allow_nan && more => state = State::Nan2a,
+        c if letter(c, b's') && options.allow_nan && more => {
           signaling = true;
           state = State::Nan1n
         }
-        _ => return Value::NaN(sign, signaling),
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::LeadingZerosBefore => match ch {
-        '0' => {}
-        '1'..='9' => {
-          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact);
+        b'0' => digits_seen = true,
+        b'1'..=b'9' => {
+          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact, truncated);
+          digits_seen = true;
           state = State::DigitsBefore;
         }
-        '.' => state = State::DigitsAfter,
-        'E' | 'e' => state = State::ExponentSign,
-        'i' | 'I' => state = State::Inf2n,
-        'n' | 'N' => state = State::Nan2a,
-        's' | 'S' => {
+        b'.' if digits_seen || options.allow_bare_point => state = State::DigitsAfter,
+        b'E' | b'e' if !strict || digits_seen => state = State::ExponentSign,
+        c if letter(c, b'I') && options.allow_infinity && (!strict || !digits_seen) => state = State::Inf2n,
+        c if letter(c, b'N') && options.allow_nan && (!strict || !digits_seen) => state = State::Nan2a,
+        c if letter(c, b's') && options.allow_nan && (!strict || !digits_seen) => {
           signaling = true;
           state = State::Nan1n
         }
-        _ => return Value::NaN(sign, signaling),
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::DigitsBefore => match ch {
-        '0'..='9' => {
+        b'0'..=b'9' => {
           if digits == max_digits {
             exp += 1;
           }
-          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact)
+          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact, truncated)
         }
-        '.' => state = State::DigitsAfter,
-        'E' | 'e' => state = State::ExponentSign,
-        _ => return Value::NaN(sign, signaling),
+        b'.' => state = State::DigitsAfter,
+        b'E' | b'e' => state = State::ExponentSign,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::DigitsAfter => match ch {
-        '0'..='9' => {
+        b'0'..=b'9' => {
           if digits < max_digits {
             exp -= 1;
           }
-          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact);
+          update_value!(val, ch, digits, max_digits, digits_total, buffer, inexact, truncated);
+          digits_seen = true;
+          fraction_digits_seen = true;
+        }
+        b'E' | b'e' if more && (!strict || digits_seen) && (fraction_digits_seen || options.allow_bare_point) => {
+          state = State::ExponentSign
         }
-        'E' | 'e' if position < last => state = State::ExponentSign,
-        _ => return Value::NaN(sign, signaling),
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::ExponentSign => match ch {
-        '+' | '0' if position < last => state = State::ExponentLeadingZeros,
-        '-' if position < last => {
+        b'+' if more => state = State::ExponentLeadingZeros,
+        b'-' if more => {
           exp_sign = -1_i32;
           state = State::ExponentLeadingZeros;
         }
-        '1'..='9' => {
+        b'0' => {
+          exp_digits_seen = true;
+          state = State::ExponentLeadingZeros;
+        }
+        b'1'..=b'9' => {
           update_exponent!(exp_base, ch);
           state = State::ExponentDigits;
         }
-        _ => return Value::NaN(sign, signaling),
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::ExponentLeadingZeros => match ch {
-        '0' => {}
-        '1'..='9' => {
+        b'0' => exp_digits_seen = true,
+        b'1'..=b'9' => {
           update_exponent!(exp_base, ch);
           state = State::ExponentDigits;
         }
+        _ if strict => invalid!(UnexpectedCharacter, position),
         _ => break,
       },
       State::ExponentDigits => match ch {
-        '0'..='9' => {
+        b'0'..=b'9' => {
           update_exponent!(exp_base, ch);
         }
+        _ if strict => invalid!(UnexpectedCharacter, position),
         _ => break,
       },
       State::Inf2n => match ch {
-        'n' | 'N' if position < last => state = State::Inf3f,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'n') && more => state = State::Inf3f,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf3f => match ch {
-        'f' | 'F' if position == last => inf = true,
-        'f' | 'F' => state = State::Inf4i,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'f') => state = State::Inf4i,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf4i => match ch {
-        'i' | 'I' if position < last => state = State::Inf5n,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'i') && more => state = State::Inf5n,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf5n => match ch {
-        'n' | 'N' if position < last => state = State::Inf6i,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'n') && more => state = State::Inf6i,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf6i => match ch {
-        'i' | 'I' if position < last => state = State::Inf7t,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'i') && more => state = State::Inf7t,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf7t => match ch {
-        't' | 'T' if position < last => state = State::Inf8y,
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b't') && more => state = State::Inf8y,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Inf8y => match ch {
-        'y' | 'Y' if position == last => {
-          inf = true;
-          break;
-        }
-        _ => return Value::NaN(sign, signaling),
+        c if letter(c, b'y') => state = State::InfEnd,
+        _ => invalid!(UnexpectedCharacter, position),
       },
+      State::InfEnd => invalid!(UnexpectedCharacter, position),
       State::Nan1n => match ch {
-        'n' | 'N' if position < last => state = State::Nan2a,
-        _ => return Value::NaN(sign, false),
+        c if letter(c, b'N') && more => state = State::Nan2a,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Nan2a => match ch {
-        'a' | 'A' if position < last => state = State::Nan3n,
-        _ => return Value::NaN(sign, false),
+        c if letter(c, b'a') && more => state = State::Nan3n,
+        _ => invalid!(UnexpectedCharacter, position),
       },
       State::Nan3n => match ch {
-        'n' | 'N' => {
-          nan = true;
-          break;
+        c if letter(c, b'N') => state = State::NanPayload,
+        _ => invalid!(UnexpectedCharacter, position),
+      },
+      State::NanPayload => match ch {
+        b'0'..=b'9' => {
+          payload = payload.saturating_mul(10).saturating_add((ch - b'0') as u128);
         }
-        _ => return Value::NaN(sign, false),
+        _ => invalid!(UnexpectedCharacter, position),
       },
     }
+    // count the digits in the current group, validate the last group when the part ends
+    if state.part() != part {
+      if grouped {
+        check_grouping!(if part == 1 { group == 3 } else { group <= 3 }, position);
+      }
+      grouped = false;
+      group = 0;
+    }
+    if ch.is_ascii_digit() && state.part() > 0 {
+      group += 1;
+    }
+    // remember the end of the longest complete number recognized so far
+    if prefix
+      && match state {
+        State::LeadingZerosBefore => digits_seen,
+        State::DigitsAfter => digits_seen && (fraction_digits_seen || options.allow_bare_point),
+        State::ExponentLeadingZeros => exp_digits_seen,
+        State::DigitsBefore | State::ExponentDigits | State::Inf4i | State::InfEnd | State::NanPayload => true,
+        _ => false,
+      }
+    {
+      accepted = position + width;
+    }
+  }
+  let consumed = if prefix && accepted > 0 { accepted } else { input.len() };
+  if grouped {
+    check_grouping!(if state.part() == 1 { group == 3 } else { group <= 3 }, consumed);
   }
 
-  // check for infinity
+  // check for infinity, after "inf" the prefix may end inside of "infinity"
+  let inf = match state {
+    State::Inf4i | State::InfEnd => true,
+    State::Inf5n | State::Inf6i | State::Inf7t | State::Inf8y => prefix && accepted > 0,
+    _ => false,
+  };
   if inf {
     // return +/-infinity
-    return Value::Infinity(sign);
+    return (Scanned::Special(Value::Infinity(sign)), consumed);
   }
 
   // check for invalid number
-  if nan {
-    // return +/-[s]nan
-    return Value::NaN(sign, signaling);
+  if let State::NanPayload = state {
+    // return +/-[s]nan with payload
+    return (Scanned::Special(Value::NaN(sign, signaling, payload)), consumed);
+  }
+
+  // check if the number is complete
+  if strict && accepted == 0 {
+    let kind = match state {
+      State::BeginNumber => Some(ParseErrorKind::Empty),
+      State::LeadingZerosBefore | State::DigitsAfter if !digits_seen => Some(ParseErrorKind::UnexpectedEnd),
+      State::DigitsAfter if !fraction_digits_seen && !options.allow_bare_point => Some(ParseErrorKind::UnexpectedEnd),
+      State::LeadingZerosBefore | State::DigitsBefore | State::DigitsAfter | State::ExponentDigits => None,
+      State::ExponentLeadingZeros if exp_digits_seen => None,
+      State::ExponentSign | State::ExponentLeadingZeros => Some(ParseErrorKind::MissingExponentDigits),
+      _ => Some(ParseErrorKind::UnexpectedEnd),
+    };
+    if let Some(kind) = kind {
+      return (
+        Scanned::Special(Value::Invalid(sign, ParseError::new(kind, input.len()))),
+        input.len(),
+      );
+    }
   }
 
   // calculate final exponent
   exp = exp.saturating_add(exp_sign.saturating_mul(exp_base));
 
-  // apply rounding if needed
-  let mut flags: Flags = 0_u32;
-  if digits_total > max_digits {
-    let mut carry = 0_u32;
-    let mut i = max_digits;
-    match rnd {
-      Rounding::ToNearest => {
-        carry = ((4 - (buffer[i] as i32)) as u32) >> 31;
-        if (buffer[i] == 5 && (buffer[i - 1] & 1) == 0) || exp < 0 {
-          if exp >= 0 {
-            carry = 0;
-            i += 1;
-          }
-          for b in &buffer[i..digits_total] {
-            if *b > 0 {
-              carry = 1;
-              break;
-            }
-          }
-        }
-      }
-      Rounding::Down => {
-        if sign {
-          for b in &buffer[i..digits_total] {
-            if *b > 0 {
-              carry = 1;
-              break;
-            }
-          }
-        }
-      }
-      Rounding::Up => {
-        if !sign {
-          for b in &buffer[i..digits_total] {
-            if *b > 0 {
-              carry = 1;
-              break;
-            }
-          }
+  // return finite number
+  (
+    Scanned::Finite(Digits {
+      sign,
+      value: val,
+      exponent: exp,
+      buffer,
+      count: digits_total,
+      inexact,
+      truncated,
+    }),
+    consumed,
+  )
+}
+
+impl<const N: usize> Digits<N> {
+  /// Rounds the coefficient to the specified number of digits.
+  pub fn round(&self, max_digits: usize, rnd: Rounding) -> Value {
+    let Digits {
+      sign,
+      value: mut val,
+      exponent: mut exp,
+      ref buffer,
+      count: digits_total,
+      inexact,
+      truncated,
+    } = *self;
+    // apply rounding if needed
+    let mut flags = Status::NONE;
+    if digits_total > max_digits {
+      let round = buffer[max_digits];
+      let sticky = truncated || buffer[max_digits + 1..digits_total].iter().any(|b| *b > 0);
+      // nonzero digits below the round digit make the dropped part greater than the half
+      let half = round.cmp(&5).then(sticky.cmp(&false));
+      if rnd.carry(sign, val & 1 == 1, half, round > 0 || sticky) {
+        val += 1;
+        // rounding up may produce one digit more than allowed
+        if val == 10_u128.pow(max_digits as u32) {
+          val /= 10;
+          exp = exp.saturating_add(1);
         }
       }
-      Rounding::ToZero => {
-        carry = 0;
-      }
-      Rounding::TiesAway => {
-        carry = ((4 - (buffer[i] as i32)) as u32) >> 31;
-        if exp < 0 {
-          for b in &buffer[i..digits_total] {
-            if *b > 0 {
-              carry = 1;
-              break;
-            }
-          }
-        }
+      if inexact {
+        flags |= Status::INEXACT;
       }
     }
-    val += carry as u128;
-    if inexact {
-      flags |= FLAG_INEXACT;
-    }
+    Value::Finite(sign, val, exp, flags)
   }
 
-  // return finite number
-  Value::Finite(sign, val, exp, flags)
+  /// Returns the exponent of the last significant digit stored in the buffer.
+  pub fn buffer_exponent(&self, max_digits: usize) -> i32 {
+    let stored = self.count.min(max_digits);
+    self.exponent.saturating_sub((self.count - stored) as i32)
+  }
 }
 
 #[cfg(test)]
@@ -379,4 +641,25 @@ mod tests {
     assert_eq!(0, Rounding::from(5) as u32);
     assert_eq!(0, Rounding::from(100) as u32);
   }
+
+  #[test]
+  fn test_rounding_carry() {
+    use Ordering::{Equal, Greater, Less};
+    // (sign, odd, half, sticky) -> carry in modes ToNearest, Down, Up, ToZero, TiesAway
+    let cases = [
+      ((false, false, Less, false), [false, false, false, false, false]),
+      ((false, false, Less, true), [false, false, true, false, false]),
+      ((true, false, Less, true), [false, true, false, false, false]),
+      ((false, false, Equal, true), [false, false, true, false, true]),
+      ((false, true, Equal, true), [true, false, true, false, true]),
+      ((true, true, Equal, true), [true, true, false, false, true]),
+      ((false, false, Greater, true), [true, false, true, false, true]),
+      ((true, false, Greater, true), [true, true, false, false, true]),
+    ];
+    for ((sign, odd, half, sticky), expected) in cases {
+      for (rnd, carry) in expected.into_iter().enumerate() {
+        assert_eq!(carry, Rounding::from(rnd as i32).carry(sign, odd, half, sticky));
+      }
+    }
+  }
 }
@@ -0,0 +1,275 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Exception status flags
+
+use core::fmt;
+use core::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Exception status flags reported by conversions.
+///
+/// Bits of the flags are compatible with status flags
+/// reported by Intel® Decimal Floating-Point Math Library.
+///
+/// # Examples
+///
+/// ```
+/// use scidec::{bid128_from_string, Status};
+///
+/// let (_, status) = bid128_from_string("1e6145");
+/// assert!(status.is_overflow());
+/// assert!(status.is_inexact());
+/// assert_eq!(Status::OVERFLOW | Status::INEXACT, status);
+/// assert_eq!(0x28, u32::from(status));
+/// assert_eq!("overflow | inexact", status.to_string());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Status(u32);
+
+impl Status {
+  /// No exception was raised.
+  pub const NONE: Status = Status(0x00);
+  /// Invalid operation.
+  pub const INVALID: Status = Status(0x01);
+  /// Division of a finite nonzero number by zero.
+  pub const ZERO_DIVIDE: Status = Status(0x04);
+  /// The result is too large to be represented.
+  pub const OVERFLOW: Status = Status(0x08);
+  /// The result is too small to be represented.
+  pub const UNDERFLOW: Status = Status(0x10);
+  /// The result was rounded.
+  pub const INEXACT: Status = Status(0x20);
+
+  /// All defined flags with their names, in the order of their bits.
+  const FLAGS: [(Status, &'static str); 5] = [
+    (Status::INVALID, "invalid"),
+    (Status::ZERO_DIVIDE, "zero divide"),
+    (Status::OVERFLOW, "overflow"),
+    (Status::UNDERFLOW, "underflow"),
+    (Status::INEXACT, "inexact"),
+  ];
+
+  /// Creates status flags from bits, unknown bits are ignored.
+  pub const fn from_bits_truncate(bits: u32) -> Self {
+    Self(bits & (Self::INVALID.0 | Self::ZERO_DIVIDE.0 | Self::OVERFLOW.0 | Self::UNDERFLOW.0 | Self::INEXACT.0))
+  }
+
+  /// Returns the bits of the status flags.
+  pub const fn bits(&self) -> u32 {
+    self.0
+  }
+
+  /// Returns `true` when no flag is set.
+  pub const fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  /// Returns `true` when all flags set in `other` are also set in this status.
+  pub const fn contains(&self, other: Status) -> bool {
+    self.0 & other.0 == other.0
+  }
+
+  /// Returns `true` when the invalid operation flag is set.
+  pub const fn is_invalid(&self) -> bool {
+    self.contains(Self::INVALID)
+  }
+
+  /// Returns `true` when the division by zero flag is set.
+  pub const fn is_zero_divide(&self) -> bool {
+    self.contains(Self::ZERO_DIVIDE)
+  }
+
+  /// Returns `true` when the overflow flag is set.
+  pub const fn is_overflow(&self) -> bool {
+    self.contains(Self::OVERFLOW)
+  }
+
+  /// Returns `true` when the underflow flag is set.
+  pub const fn is_underflow(&self) -> bool {
+    self.contains(Self::UNDERFLOW)
+  }
+
+  /// Returns `true` when the inexact flag is set.
+  pub const fn is_inexact(&self) -> bool {
+    self.contains(Self::INEXACT)
+  }
+
+  /// Returns an iterator over the single flags set in this status.
+  pub fn iter(&self) -> StatusIter {
+    StatusIter {
+      status: *self,
+      index: 0,
+    }
+  }
+}
+
+/// Iterator over the single flags set in [Status].
+pub struct StatusIter {
+  status: Status,
+  index: usize,
+}
+
+impl Iterator for StatusIter {
+  type Item = Status;
+
+  /// Returns the next flag set in the status.
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.index < Status::FLAGS.len() {
+      let (flag, _) = Status::FLAGS[self.index];
+      self.index += 1;
+      if self.status.contains(flag) {
+        return Some(flag);
+      }
+    }
+    None
+  }
+}
+
+impl IntoIterator for Status {
+  type Item = Status;
+  type IntoIter = StatusIter;
+
+  /// Returns an iterator over the single flags set in the status.
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl BitOr for Status {
+  type Output = Status;
+
+  /// Returns the union of status flags.
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Status(self.0 | rhs.0)
+  }
+}
+
+impl BitOrAssign for Status {
+  /// Sets the flags from `rhs`.
+  fn bitor_assign(&mut self, rhs: Self) {
+    self.0 |= rhs.0
+  }
+}
+
+impl BitAnd for Status {
+  type Output = Status;
+
+  /// Returns the intersection of status flags.
+  fn bitand(self, rhs: Self) -> Self::Output {
+    Status(self.0 & rhs.0)
+  }
+}
+
+impl From<Status> for u32 {
+  /// Converts [Status] into Intel-compatible status bits.
+  fn from(value: Status) -> Self {
+    value.0
+  }
+}
+
+impl From<u32> for Status {
+  /// Converts Intel-compatible status bits into [Status], unknown bits are ignored.
+  fn from(value: u32) -> Self {
+    Status::from_bits_truncate(value)
+  }
+}
+
+impl fmt::Display for Status {
+  /// Formats names of the flags separated with `|`, no flags are formatted as `none`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.is_empty() {
+      return f.write_str("none");
+    }
+    let names = Status::FLAGS
+      .iter()
+      .filter(|(flag, _)| self.contains(*flag))
+      .map(|(_, name)| name);
+    for (i, name) in names.enumerate() {
+      if i > 0 {
+        f.write_str(" | ")?;
+      }
+      f.write_str(name)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use alloc::string::ToString;
+  use alloc::vec::Vec;
+
+  #[test]
+  fn test_bits() {
+    assert_eq!(0x00, Status::NONE.bits());
+    assert_eq!(0x01, Status::INVALID.bits());
+    assert_eq!(0x04, Status::ZERO_DIVIDE.bits());
+    assert_eq!(0x08, Status::OVERFLOW.bits());
+    assert_eq!(0x10, Status::UNDERFLOW.bits());
+    assert_eq!(0x20, Status::INEXACT.bits());
+    assert_eq!(0x30, u32::from(Status::UNDERFLOW | Status::INEXACT));
+    assert_eq!(Status::OVERFLOW | Status::INEXACT, Status::from(0x28));
+    assert_eq!(Status::INEXACT, Status::from(0xe2));
+    assert_eq!(Status::ZERO_DIVIDE | Status::INEXACT, Status::from(0xe6));
+    assert_eq!(Status::NONE, Status::default());
+  }
+
+  #[test]
+  fn test_predicates() {
+    let mut status = Status::NONE;
+    assert!(status.is_empty());
+    status |= Status::INVALID;
+    assert!(status.is_invalid());
+    assert!(!status.is_overflow());
+    assert!(!status.is_zero_divide());
+    status |= Status::UNDERFLOW | Status::INEXACT;
+    assert!(status.is_underflow());
+    assert!(status.is_inexact());
+    assert!(status.contains(Status::UNDERFLOW | Status::INEXACT));
+    assert!(!status.contains(Status::OVERFLOW | Status::INEXACT));
+    assert_eq!(Status::INEXACT, status & (Status::OVERFLOW | Status::INEXACT));
+  }
+
+  #[test]
+  fn test_iter() {
+    let status = Status::INEXACT | Status::INVALID | Status::OVERFLOW;
+    assert_eq!(
+      [Status::INVALID, Status::OVERFLOW, Status::INEXACT],
+      status.iter().collect::<Vec<Status>>()[..]
+    );
+    assert_eq!(0, Status::NONE.into_iter().count());
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!("none", Status::NONE.to_string());
+    assert_eq!("underflow", Status::UNDERFLOW.to_string());
+    assert_eq!(
+      "invalid | overflow | underflow | inexact",
+      Status::from(0x39).to_string()
+    );
+    assert_eq!("zero divide", Status::ZERO_DIVIDE.to_string());
+  }
+}
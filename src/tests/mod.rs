@@ -24,51 +24,732 @@
 
 //! Utility functions for unit tests.
 
-use crate::bid128::bid128_from_string_rnd;
+use crate::bid128::{
+  bid128_from_bytes, bid128_from_bytes_rnd, bid128_from_string, bid128_from_string_prefix, bid128_from_string_rnd,
+  bid128_from_string_with_options, bid128_to_string, try_bid128_from_string, Bid128, Class,
+};
+use crate::bid32::bid32_from_string_rnd;
+use crate::bid64::bid64_from_string_rnd;
+use crate::binary::{f32_from_string_rnd, f64_from_string_rnd};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::options::{ParseOptions, WhitespacePolicy};
 use crate::recognizer::Rounding;
+use crate::Status;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 
 const BID128_INPUT: &str = include_str!("test_cases.in");
 
+const BID64_INPUT: &str = include_str!("bid64_test_cases.in");
+
+const BID32_INPUT: &str = include_str!("bid32_test_cases.in");
+
+const STRICT_INPUT: &str = include_str!("strict_test_cases.in");
+
+const PREFIX_INPUT: &str = include_str!("prefix_test_cases.in");
+
+const GROUPING_INPUT: &str = include_str!("grouping_test_cases.in");
+
+const OPTIONS_INPUT: &str = include_str!("options_test_cases.in");
+
+const BINARY64_INPUT: &str = include_str!("binary64_test_cases.in");
+
+const BINARY32_INPUT: &str = include_str!("binary32_test_cases.in");
+
+const FROM_F64_INPUT: &str = include_str!("from_f64_test_cases.in");
+
+const STRING_BINARY64_INPUT: &str = include_str!("string_binary64_test_cases.in");
+
+const STRING_BINARY32_INPUT: &str = include_str!("string_binary32_test_cases.in");
+
+const INTEGER_INPUT: &str = include_str!("integer_test_cases.in");
+
+const COMPARE_INPUT: &str = include_str!("compare_test_cases.in");
+
+const ADD_INPUT: &str = include_str!("add_test_cases.in");
+
+const SUB_INPUT: &str = include_str!("sub_test_cases.in");
+
+const MUL_INPUT: &str = include_str!("mul_test_cases.in");
+
+const DIV_INPUT: &str = include_str!("div_test_cases.in");
+
+const REMAINDER_INPUT: &str = include_str!("remainder_test_cases.in");
+
+const DIVIDE_INTEGER_INPUT: &str = include_str!("divide_integer_test_cases.in");
+
+const REM_INPUT: &str = include_str!("rem_test_cases.in");
+
+const FMA_INPUT: &str = include_str!("fma_test_cases.in");
+
+const SQRT_INPUT: &str = include_str!("sqrt_test_cases.in");
+
+const QUANTIZE_INPUT: &str = include_str!("quantize_test_cases.in");
+
+const ROUND_TO_INTEGRAL_INPUT: &str = include_str!("round_to_integral_test_cases.in");
+
+const ROUND_TO_INTEGRAL_EXACT_INPUT: &str = include_str!("round_to_integral_exact_test_cases.in");
+
+/// Returns the numbered lines of test cases split into columns, skipping empty lines and comments.
+fn case_lines(cases: &str) -> impl Iterator<Item = (usize, Vec<&str>)> {
+  cases
+    .lines()
+    .enumerate()
+    .map(|(i, line)| (i + 1, line.trim()))
+    .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+    .map(|(line_no, line)| (line_no, line.split(' ').collect()))
+}
+
+/// Returns the input text from the quoted column, underscores stand for spaces.
+fn input(column: &str) -> String {
+  column.trim_matches('"').replace('_', " ")
+}
+
+/// Returns the kind of error named in test cases.
+fn error_kind(name: &str) -> Option<ParseErrorKind> {
+  match name {
+    "Empty" => Some(ParseErrorKind::Empty),
+    "UnexpectedCharacter" => Some(ParseErrorKind::UnexpectedCharacter),
+    "UnexpectedEnd" => Some(ParseErrorKind::UnexpectedEnd),
+    "MissingExponentDigits" => Some(ParseErrorKind::MissingExponentDigits),
+    "InvalidGrouping" => Some(ParseErrorKind::InvalidGrouping),
+    "InvalidOptions" => Some(ParseErrorKind::InvalidOptions),
+    _ => None,
+  }
+}
+
+/// Checks the encoded result and status against the expected columns,
+/// the higher and the lower word of 128-bit results are separated with a comma.
+fn check_bits(line_no: usize, expected: &str, expected_status: &str, actual: u128, actual_status: Status) {
+  let expected = expected.trim_matches('[').trim_matches(']').replace(',', "");
+  let width = expected.len();
+  let expected = u128::from_str_radix(&expected, 16).unwrap();
+  let expected_status = u32::from_str_radix(expected_status, 16).unwrap();
+  let actual_status = actual_status.bits();
+  assert_eq!(
+    expected, actual,
+    "[{}] bits:\nexpected: {expected:0width$x}\n  actual: {actual:0width$x}\n",
+    line_no
+  );
+  assert_eq!(
+    expected_status, actual_status,
+    "[{}] status:\nexpected: {expected_status:02x}\n  actual: {actual_status:02x}\n",
+    line_no
+  );
+}
+
+/// Returns the bits of the 128-bit decimal.
+fn bits(value: &Bid128) -> u128 {
+  (value.w[1] as u128) << 64 | value.w[0] as u128
+}
+
+/// Checks the results of a conversion against test cases,
+/// each line contains rounding mode, input, expected bits and status.
+fn check_conversion_cases(cases: &str, convert: impl Fn(&str, Rounding) -> (u128, Status)) {
+  for (line_no, columns) in case_lines(cases) {
+    let rounding = columns[0].parse::<i32>().unwrap();
+    let (actual, actual_status) = convert(&input(columns[1]), rounding.into());
+    check_bits(line_no, columns[2], columns[3], actual, actual_status);
+  }
+}
+
+/// Checks the results of parsing against test cases, each line contains the columns used by `parse`,
+/// followed by the expected result checked by `check`, or by the kind and the offset of the expected error.
+fn check_parse_cases<T>(cases: &str, parse: impl Fn(&[&str]) -> Result<T, ParseError>, check: impl Fn(usize, &str, T)) {
+  for (line_no, columns) in case_lines(cases) {
+    let expected = columns[columns.len() - 1];
+    let expected_error = error_kind(columns[columns.len() - 2]);
+    match (parse(&columns), expected_error) {
+      (Ok(actual), None) => check(line_no, expected, actual),
+      (Ok(_), Some(kind)) => panic!("[{}] expected: {kind:?}, actual: ok", line_no),
+      (Err(err), None) => panic!("[{}] expected: {expected}, actual: {err}", line_no),
+      (Err(err), Some(kind)) => {
+        assert_eq!(kind, err.kind(), "[{}] kind", line_no);
+        assert_eq!(expected.parse::<usize>().unwrap(), err.offset(), "[{}] offset", line_no);
+      }
+    }
+  }
+}
+
 #[test]
 fn test_input_cases() {
-  for (i, mut line) in BID128_INPUT.lines().enumerate() {
-    line = line.trim();
-    if !line.is_empty() && !line.starts_with('#') {
-      let mut columns = line.split(' ');
-      let rounding = columns.next().unwrap().parse::<i32>().unwrap();
-      let input = columns.next().unwrap().trim_matches('"').replace('_', " ");
-      let mut bid = columns.next().unwrap().trim_matches('[').trim_matches(']').split(',');
-      let expected_w1 = u64::from_str_radix(bid.next().unwrap(), 16).unwrap();
-      let expected_w0 = u64::from_str_radix(bid.next().unwrap(), 16).unwrap();
-      let expected_status = u32::from_str_radix(columns.next().unwrap(), 16).unwrap();
-      let (actual, actual_status) = bid128_from_string_rnd(&input, rounding.into());
-      let actual_w1 = actual.w[1];
-      let actual_w0 = actual.w[0];
-      let line_no = i + 1;
-      assert_eq!(
-        expected_w1, actual_w1,
-        "[{}] w1:\nexpected: {expected_w1:016x}\n  actual: {actual_w1:016x}\n",
-        line_no
-      );
+  check_conversion_cases(BID128_INPUT, |input, rnd| {
+    let (actual, status) = bid128_from_string_rnd(input, rnd);
+    (bits(&actual), status)
+  });
+}
+
+#[test]
+fn test_bid128_round_trip() {
+  for (line_no, columns) in case_lines(BID128_INPUT) {
+    let input = input(columns[1]);
+    let (expected, _) = bid128_from_string_rnd(&input, columns[0].parse::<i32>().unwrap().into());
+    let text = bid128_to_string(&expected);
+    let (actual, actual_status) = bid128_from_string(&text);
+    assert_eq!(
+      expected.w, actual.w,
+      "[{}] {input} -> {text}:\nexpected: {:016x} {:016x}\n  actual: {:016x} {:016x}\n",
+      line_no, expected.w[1], expected.w[0], actual.w[1], actual.w[0]
+    );
+    assert!(actual_status.is_empty(), "[{}] {input} -> {text}", line_no);
+  }
+}
+
+#[test]
+fn test_bid64_input_cases() {
+  check_conversion_cases(BID64_INPUT, |input, rnd| {
+    let (actual, status) = bid64_from_string_rnd(input, rnd);
+    (actual.w as u128, status)
+  });
+}
+
+#[test]
+fn test_bid32_input_cases() {
+  check_conversion_cases(BID32_INPUT, |input, rnd| {
+    let (actual, status) = bid32_from_string_rnd(input, rnd);
+    (actual.w as u128, status)
+  });
+}
+
+#[test]
+fn test_strict_input_cases() {
+  check_parse_cases(
+    STRICT_INPUT,
+    |columns| try_bid128_from_string(&input(columns[0])).map(|result| (input(columns[0]), result)),
+    |line_no, expected, (input, (actual, actual_status))| {
+      assert_eq!("ok", expected, "[{}] expected error, actual: {actual}", line_no);
+      let (expected, expected_status) = bid128_from_string(&input);
+      assert_eq!(expected.w, actual.w, "[{}] strict and lenient values differ", line_no);
       assert_eq!(
-        expected_w0, actual_w0,
-        "[{}] w0:\nexpected: {expected_w0:016x}\n  actual: {actual_w0:016x}\n",
+        expected_status, actual_status,
+        "[{}] strict and lenient status differ",
         line_no
       );
+    },
+  );
+}
+
+#[test]
+fn test_prefix_input_cases() {
+  check_parse_cases(
+    PREFIX_INPUT,
+    |columns| bid128_from_string_prefix(&input(columns[0])).map(|result| (input(columns[0]), result)),
+    |line_no, expected, (input, (actual, actual_status, consumed))| {
+      assert_eq!(expected.parse::<usize>().unwrap(), consumed, "[{}] consumed", line_no);
+      let (expected, expected_status) = try_bid128_from_string(&input[..consumed]).unwrap();
+      assert_eq!(expected.w, actual.w, "[{}] prefix and strict values differ", line_no);
       assert_eq!(
         expected_status, actual_status,
-        "[{}] status:\nexpected: {expected_status:02x}\n  actual: {actual_status:02x}\n",
+        "[{}] prefix and strict status differ",
         line_no
       );
+    },
+  );
+}
+
+/// Checks the number parsed with options against the same number parsed from the expected text.
+fn check_equivalent(line_no: usize, expected: &str, (actual, actual_status): (Bid128, Status)) {
+  let (expected, expected_status) = try_bid128_from_string(expected.trim_matches('"'))
+    .unwrap_or_else(|_| panic!("[{}] expected: {expected}, actual: {actual}", line_no));
+  assert_eq!(expected.w, actual.w, "[{}] values differ", line_no);
+  assert_eq!(expected_status, actual_status, "[{}] status differ", line_no);
+}
+
+#[test]
+fn test_grouping_input_cases() {
+  check_parse_cases(
+    GROUPING_INPUT,
+    |columns| {
+      let options = ParseOptions {
+        decimal_mark: columns[0].chars().nth(1).unwrap(),
+        group_separator: columns[1].chars().nth(1),
+        strict_grouping: columns[2] == "1",
+        ..Default::default()
+      };
+      bid128_from_string_with_options(columns[3].trim_matches('"'), Rounding::ToNearest, &options)
+    },
+    check_equivalent,
+  );
+}
+
+#[test]
+fn test_options_input_cases() {
+  check_parse_cases(
+    OPTIONS_INPUT,
+    |columns| {
+      let options = columns[0]
+        .split(',')
+        .fold(ParseOptions::new(), |options, name| match name {
+          "default" => options,
+          "nows" => options.with_whitespace(WhitespacePolicy::REJECT),
+          "trim" => options.with_whitespace(WhitespacePolicy::TRIM),
+          "trailing-ws" => options.with_whitespace(WhitespacePolicy {
+            allow_leading: false,
+            allow_trailing: true,
+          }),
+          "noplus" => options.with_leading_plus(false),
+          "nobare" => options.with_bare_point(false),
+          "noinf" => options.with_infinity(false),
+          "nonan" => options.with_nan(false),
+          "case" => options.with_case_sensitive(true),
+          "trailing" => options.with_ignore_trailing(true),
+          "comma-mark" => options.with_decimal_mark(','),
+          "comma-group" => options.with_group_separator(','),
+          "e-mark" => options.with_decimal_mark('e'),
+          "e-group" => options.with_group_separator('E'),
+          "digit-group" => options.with_group_separator('1'),
+          "minus-group" => options.with_group_separator('-'),
+          "plus-mark" => options.with_decimal_mark('+'),
+          "n-mark" => options.with_decimal_mark('n'),
+          "i-mark" => options.with_decimal_mark('i'),
+          "a-mark" => options.with_decimal_mark('a'),
+          "s-mark" => options.with_decimal_mark('s'),
+          "space-mark" => options.with_decimal_mark(' '),
+          "tab-mark" => options.with_decimal_mark('\t'),
+          "space-group" => options.with_group_separator(' '),
+          other => panic!("unknown option: {other}"),
+        });
+      bid128_from_string_with_options(&input(columns[1]), Rounding::ToNearest, &options)
+    },
+    check_equivalent,
+  );
+}
+
+#[test]
+fn test_to_f64_input_cases() {
+  check_conversion_cases(BINARY64_INPUT, |input, rnd| {
+    let (actual, status) = bid128_from_string(input).0.to_f64_rnd(rnd);
+    (actual.to_bits() as u128, status)
+  });
+}
+
+#[test]
+fn test_to_f32_input_cases() {
+  check_conversion_cases(BINARY32_INPUT, |input, rnd| {
+    let (actual, status) = bid128_from_string(input).0.to_f32_rnd(rnd);
+    (actual.to_bits() as u128, status)
+  });
+}
+
+#[test]
+fn test_f64_from_string_input_cases() {
+  check_conversion_cases(STRING_BINARY64_INPUT, |input, rnd| {
+    let (actual, status) = f64_from_string_rnd(input, rnd);
+    (actual.to_bits() as u128, status)
+  });
+}
+
+#[test]
+fn test_f32_from_string_input_cases() {
+  check_conversion_cases(STRING_BINARY32_INPUT, |input, rnd| {
+    let (actual, status) = f32_from_string_rnd(input, rnd);
+    (actual.to_bits() as u128, status)
+  });
+}
+
+#[test]
+fn test_from_f64_input_cases() {
+  check_conversion_cases(FROM_F64_INPUT, |input, rnd| {
+    let input = u64::from_str_radix(input.trim_matches('[').trim_matches(']'), 16).unwrap();
+    let (actual, status) = Bid128::from_f64_rnd(f64::from_bits(input), rnd);
+    (bits(&actual), status)
+  });
+}
+
+#[test]
+fn test_f32_round_trip() {
+  // 34 decimal digits are always enough to convert back to the same number
+  for bits in (0_u32..0x7f800000)
+    .step_by(0x10001)
+    .chain([0x00000001, 0x007fffff, 0x7f7fffff])
+  {
+    for sign in [0, 0x80000000] {
+      let value = f32::from_bits(sign | bits);
+      let (bid, _) = Bid128::from_f32_rnd(value, Rounding::ToNearest);
+      let (actual, _) = bid.to_f32_rnd(Rounding::ToNearest);
+      assert_eq!(value.to_bits(), actual.to_bits(), "{value:e}");
+    }
+  }
+}
+
+#[test]
+fn test_bytes_input_cases() {
+  for (i, mut line) in BID128_INPUT.lines().chain(STRICT_INPUT.lines()).enumerate() {
+    line = line.trim();
+    if !line.is_empty() && !line.starts_with('#') {
+      let input = line.split(' ').find(|column| column.starts_with('"')).unwrap();
+      let input = input.trim_matches('"').replace('_', " ");
+      for rounding in 0..5 {
+        let (expected, expected_status) = bid128_from_string_rnd(&input, rounding.into());
+        let (actual, actual_status) = bid128_from_bytes_rnd(input.as_bytes(), rounding.into());
+        assert_eq!(expected.w, actual.w, "[{}] string and bytes values differ", i + 1);
+        assert_eq!(
+          expected_status,
+          actual_status,
+          "[{}] string and bytes status differ",
+          i + 1
+        );
+      }
     }
   }
 }
 
+#[test]
+fn test_bytes_not_utf8() {
+  for input in [&b"\xff"[..], b"1\xff", b"-\x80", b"1.\x80", b"\xc3\xa91"] {
+    let (actual, actual_status) = bid128_from_bytes(input);
+    assert_eq!(
+      0x7c00000000000000,
+      actual.w[1] & 0x7fffffffffffffff,
+      "{:016x}",
+      actual.w[1]
+    );
+    assert_eq!(0x0000000000000000, actual.w[0], "{:016x}", actual.w[0]);
+    assert!(actual_status.is_invalid());
+  }
+}
+
 #[test]
 fn test_check() {
   let s = "na";
   let (actual, actual_status) = bid128_from_string_rnd(s, Rounding::ToNearest);
   assert_eq!(0x7c00000000000000, actual.w[1], "{:016x}", actual.w[1]);
   assert_eq!(0x0000000000000000, actual.w[0], "{:016x}", actual.w[0]);
-  assert_eq!(0x00, actual_status);
+  assert!(actual_status.is_invalid());
+}
+
+#[test]
+fn test_to_integer_input_cases() {
+  for (i, mut line) in INTEGER_INPUT.lines().enumerate() {
+    line = line.trim();
+    if !line.is_empty() && !line.starts_with('#') {
+      let mut columns = line.split(' ');
+      let rounding = columns.next().unwrap().parse::<i32>().unwrap();
+      let input = columns.next().unwrap().trim_matches('"');
+      let integer_type = columns.next().unwrap();
+      let expected = columns.next().unwrap().parse::<i128>().unwrap();
+      let expected_status = u32::from_str_radix(columns.next().unwrap(), 16).unwrap();
+      let (value, _) = bid128_from_string_rnd(input, rounding.into());
+      let (actual, actual_status) = match integer_type {
+        "i64" => {
+          let (actual, status) = value.to_i64_rnd(rounding.into());
+          (actual as i128, status)
+        }
+        "u64" => {
+          let (actual, status) = value.to_u64_rnd(rounding.into());
+          (actual as i128, status)
+        }
+        _ => value.to_i128_rnd(rounding.into()),
+      };
+      let actual_status = actual_status.bits();
+      let line_no = i + 1;
+      assert_eq!(expected, actual, "[{}] value", line_no);
+      assert_eq!(
+        expected_status, actual_status,
+        "[{}] status:\nexpected: {expected_status:02x}\n  actual: {actual_status:02x}\n",
+        line_no
+      );
+    }
+  }
+}
+
+#[test]
+fn test_from_integer() {
+  for (value, expected) in [
+    (0_i64, [0x0000000000000000, 0x3040000000000000]),
+    (1, [0x0000000000000001, 0x3040000000000000]),
+    (-1, [0x0000000000000001, 0xb040000000000000]),
+    (i64::MAX, [0x7fffffffffffffff, 0x3040000000000000]),
+    (i64::MIN, [0x8000000000000000, 0xb040000000000000]),
+  ] {
+    assert_eq!(expected, Bid128::from(value).w, "{value}");
+  }
+  for (value, expected) in [
+    (0_u128, "0"),
+    (9999999999999999999999999999999999, "9999999999999999999999999999999999"),
+    (
+      99999999999999999999999999999999995,
+      "1.000000000000000000000000000000000E+35",
+    ),
+    (
+      12345678901234567890123456789012345,
+      "1.234567890123456789012345678901234E+34",
+    ),
+    (
+      12345678901234567890123456789012355,
+      "1.234567890123456789012345678901236E+34",
+    ),
+    (u128::MAX, "3.402823669209384634633746074317682E+38"),
+  ] {
+    assert_eq!(expected, bid128_to_string(&Bid128::from(value)), "{value}");
+  }
+}
+
+#[test]
+fn test_accessors() {
+  for (input, class, coefficient, exponent) in [
+    ("sNaN12", Class::SignalingNaN, 12, 0),
+    ("-NaN", Class::QuietNaN, 0, 0),
+    ("-Inf", Class::NegativeInfinity, 0, 0),
+    ("-1.5", Class::NegativeNormal, 15, -1),
+    ("-1e-6176", Class::NegativeSubnormal, 1, -6176),
+    ("-0e10", Class::NegativeZero, 0, 10),
+    ("0", Class::PositiveZero, 0, 0),
+    (
+      "1.00000000000000000000000000000000e-6144",
+      Class::PositiveSubnormal,
+      10_u128.pow(32),
+      -6176,
+    ),
+    ("1e-6143", Class::PositiveNormal, 1, -6143),
+    (
+      "9.999999999999999999999999999999999e6144",
+      Class::PositiveNormal,
+      10_u128.pow(34) - 1,
+      6111,
+    ),
+    ("Inf", Class::PositiveInfinity, 0, 0),
+  ] {
+    let (value, _) = bid128_from_string(input);
+    assert_eq!(class, value.class(), "{input}");
+    assert_eq!(coefficient, value.coefficient(), "{input}");
+    assert_eq!(exponent, value.exponent(), "{input}");
+    assert_eq!(input.starts_with('-'), value.is_sign_negative(), "{input}");
+    assert_eq!(input.contains("NaN"), value.is_nan(), "{input}");
+    assert_eq!(input.starts_with("sNaN"), value.is_snan(), "{input}");
+    assert_eq!(input.contains("Inf"), value.is_infinite(), "{input}");
+    assert_eq!(!value.is_nan() && !value.is_infinite(), value.is_finite(), "{input}");
+    assert_eq!(
+      class == Class::PositiveZero || class == Class::NegativeZero,
+      value.is_zero(),
+      "{input}"
+    );
+    assert!(value.is_canonical(), "{input}");
+  }
+}
+
+#[test]
+fn test_from_parts() {
+  let value = Bid128::from_parts(false, 10_u128.pow(34) - 1, -6176).unwrap();
+  assert_eq!([0x378d8e63ffffffff, 0x0001ed09bead87c0], value.w);
+  assert!(value.is_canonical());
+  let value = Bid128::from_parts(true, 0, 6111).unwrap();
+  assert_eq!([0x0000000000000000, 0xdffe000000000000], value.w);
+  assert!(value.is_zero());
+  // coefficients greater than 34 digits are not canonical and are treated as zero
+  let value = Bid128::from_parts(false, 10_u128.pow(34), 0).unwrap();
+  assert!(!value.is_canonical());
+  assert!(value.is_zero());
+  assert_eq!(0, value.exponent());
+  // coefficients in the second form
+  let value = Bid128::from_parts(true, (1 << 113) + 5, 2).unwrap();
+  assert_eq!([0x0000000000000005, 0xec11000000000000], value.w);
+  assert!(!value.is_canonical());
+  assert!(value.is_zero());
+  assert!(value.is_sign_negative());
+  assert_eq!(2, value.exponent());
+  assert_eq!(Class::NegativeZero, value.class());
+  assert!(Bid128::from_parts(false, (1 << 113) + (1 << 111) - 1, 0).is_some());
+  assert!(Bid128::from_parts(false, (1 << 113) + (1 << 111), 0).is_none());
+  assert!(Bid128::from_parts(false, 1, -6177).is_none());
+  assert!(Bid128::from_parts(false, 1, 6112).is_none());
+  // non-canonical infinities and NaNs
+  assert!(!Bid128 {
+    w: [1, 0x7800000000000000]
+  }
+  .is_canonical());
+  assert!(!Bid128 {
+    w: [0, 0x7c01000000000000]
+  }
+  .is_canonical());
+  assert!(!Bid128 {
+    w: [u64::MAX, 0x7c003fffffffffff]
+  }
+  .is_canonical());
+}
+
+#[test]
+fn test_compare_input_cases() {
+  for (i, mut line) in COMPARE_INPUT.lines().enumerate() {
+    line = line.trim();
+    if !line.is_empty() && !line.starts_with('#') {
+      let mut columns = line.split(' ');
+      let (a, _) = bid128_from_string(columns.next().unwrap().trim_matches('"'));
+      let (b, _) = bid128_from_string(columns.next().unwrap().trim_matches('"'));
+      let expected = match columns.next().unwrap() {
+        "<" => Some(Ordering::Less),
+        "=" => Some(Ordering::Equal),
+        ">" => Some(Ordering::Greater),
+        _ => None,
+      };
+      let expected_total = match columns.next().unwrap() {
+        "<" => Ordering::Less,
+        "=" => Ordering::Equal,
+        _ => Ordering::Greater,
+      };
+      let line_no = i + 1;
+      assert_eq!(expected, a.partial_cmp(&b), "[{}] partial_cmp", line_no);
+      assert_eq!(expected == Some(Ordering::Equal), a == b, "[{}] eq", line_no);
+      assert_eq!(expected_total, a.total_cmp(&b), "[{}] total_cmp", line_no);
+      if a == b {
+        assert_eq!(hash(&a), hash(&b), "[{}] hash", line_no);
+      }
+    }
+  }
+}
+
+/// FNV-1a hasher used in tests.
+struct FnvHasher(u64);
+
+impl Hasher for FnvHasher {
+  fn finish(&self) -> u64 {
+    self.0
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100000001b3);
+    }
+  }
+}
+
+/// Returns the hash of the value.
+fn hash<T: Hash>(value: &T) -> u64 {
+  let mut hasher = FnvHasher(0xcbf29ce484222325);
+  value.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Checks the results of an arithmetic operation against test cases,
+/// each line contains rounding mode, operands, expected result and status.
+fn check_operation_cases(cases: &str, operation: impl Fn(&[Bid128], Rounding) -> (Bid128, Status)) {
+  for (line_no, columns) in case_lines(cases) {
+    let rounding = columns[0].parse::<i32>().unwrap();
+    let operands: Vec<Bid128> = columns[1..columns.len() - 2]
+      .iter()
+      .map(|operand| bid128_from_string(operand.trim_matches('"')).0)
+      .collect();
+    let (actual, actual_status) = operation(&operands, rounding.into());
+    check_bits(
+      line_no,
+      columns[columns.len() - 2],
+      columns[columns.len() - 1],
+      bits(&actual),
+      actual_status,
+    );
+  }
+}
+
+#[test]
+fn test_add_input_cases() {
+  check_operation_cases(ADD_INPUT, |operands, rnd| operands[0].add_rnd(&operands[1], rnd));
+}
+
+#[test]
+fn test_sub_input_cases() {
+  check_operation_cases(SUB_INPUT, |operands, rnd| operands[0].sub_rnd(&operands[1], rnd));
+}
+
+#[test]
+fn test_mul_input_cases() {
+  check_operation_cases(MUL_INPUT, |operands, rnd| operands[0].mul_rnd(&operands[1], rnd));
+}
+
+#[test]
+fn test_div_input_cases() {
+  check_operation_cases(DIV_INPUT, |operands, rnd| operands[0].div_rnd(&operands[1], rnd));
+}
+
+#[test]
+fn test_remainder_input_cases() {
+  check_operation_cases(REMAINDER_INPUT, |operands, _| operands[0].remainder(&operands[1]));
+}
+
+#[test]
+fn test_divide_integer_input_cases() {
+  check_operation_cases(DIVIDE_INTEGER_INPUT, |operands, _| {
+    operands[0].divide_integer(&operands[1])
+  });
+}
+
+#[test]
+fn test_rem_input_cases() {
+  check_operation_cases(REM_INPUT, |operands, _| operands[0].rem(&operands[1]));
+}
+
+#[test]
+fn test_fma_input_cases() {
+  check_operation_cases(FMA_INPUT, |operands, rnd| {
+    operands[0].fma_rnd(&operands[1], &operands[2], rnd)
+  });
+}
+
+#[test]
+fn test_sqrt_input_cases() {
+  check_operation_cases(SQRT_INPUT, |operands, rnd| operands[0].sqrt_rnd(rnd));
+}
+
+#[test]
+fn test_quantize_input_cases() {
+  check_operation_cases(QUANTIZE_INPUT, |operands, rnd| operands[0].quantize(&operands[1], rnd));
+}
+
+#[test]
+fn test_rescale_input_cases() {
+  check_operation_cases(QUANTIZE_INPUT, |operands, rnd| {
+    if operands[1].is_finite() {
+      operands[0].rescale(operands[1].exponent(), rnd)
+    } else {
+      operands[0].quantize(&operands[1], rnd)
+    }
+  });
+}
+
+#[test]
+fn test_rescale() {
+  let (a, _) = bid128_from_string("1.5");
+  let (result, status) = a.rescale(-33, Rounding::ToNearest);
+  assert_eq!("1.500000000000000000000000000000000", bid128_to_string(&result));
+  assert!(status.is_empty());
+  for exponent in [-34, -6176, -6177, 6112, i32::MIN, i32::MAX] {
+    let (result, status) = a.rescale(exponent, Rounding::ToNearest);
+    assert!(result.is_nan());
+    assert_eq!(Status::INVALID, status);
+  }
+  let (result, status) = a.rescale(6111, Rounding::Up);
+  assert_eq!("1E+6111", bid128_to_string(&result));
+  assert_eq!(Status::INEXACT, status);
+  let (inf, _) = bid128_from_string("-Inf");
+  let (result, status) = inf.rescale(0, Rounding::ToNearest);
+  assert!(result.is_nan());
+  assert_eq!(Status::INVALID, status);
+}
+
+#[test]
+fn test_round_to_integral_input_cases() {
+  check_operation_cases(ROUND_TO_INTEGRAL_INPUT, |operands, rnd| {
+    operands[0].round_to_integral(rnd)
+  });
+}
+
+#[test]
+fn test_round_to_integral_exact_input_cases() {
+  check_operation_cases(ROUND_TO_INTEGRAL_EXACT_INPUT, |operands, rnd| {
+    operands[0].round_to_integral_exact(rnd)
+  });
+}
+
+#[test]
+fn test_same_quantum() {
+  let same_quantum = |a: &str, b: &str| bid128_from_string(a).0.same_quantum(&bid128_from_string(b).0);
+  assert!(same_quantum("1.00", "-0.01"));
+  assert!(same_quantum("0e5", "1e5"));
+  assert!(same_quantum("NaN", "-sNaN7"));
+  assert!(same_quantum("Inf", "-Inf"));
+  assert!(!same_quantum("1.0", "1.00"));
+  assert!(!same_quantum("1", "Inf"));
+  assert!(!same_quantum("NaN", "Inf"));
+  assert!(!same_quantum("0", "NaN"));
 }
@@ -27,7 +27,13 @@
 //! Functions exposed by this library are intensively tested using unit tests.
 //! Smoke tests check only the correctness of the library interface.
 
-use scidec::{bid128_from_string, number_from_string, Number};
+use scidec::{
+  bid128_from_bytes, bid128_from_string, bid128_from_string_prefix, bid128_from_string_with_options, bid32_from_string,
+  bid64_from_string, f32_from_string_rnd, f64_from_string_rnd, number_from_bytes, number_from_string,
+  number_from_string_prefix, number_from_string_with_options, try_bid128_from_string, try_number_from_string, Bid128,
+  Class, FromStrError, Number, ParseErrorKind, ParseOptions, RangePolicy, Rounding, Status, WhitespacePolicy,
+};
+use std::hash::{BuildHasher, RandomState};
 
 #[test]
 fn test_number_from_string() {
@@ -39,5 +45,370 @@ fn test_bid128_from_string() {
   let (actual, status) = bid128_from_string("0.00003E-02");
   assert_eq!(0x3032000000000000, actual.w[1]);
   assert_eq!(0x0000000000000003, actual.w[0]);
-  assert_eq!(0x0, status);
+  assert!(status.is_empty());
+}
+
+#[test]
+fn test_display_padding() {
+  let (value, _) = bid128_from_string("-1.50");
+  assert_eq!("   -1.50", format!("{:>8}", value));
+  assert_eq!("-1.50***", format!("{:*<8}", value));
+  assert_eq!("  1.5E+3", format!("{:>8}", number_from_string("15e2")));
+}
+
+#[test]
+fn test_bid64_from_string() {
+  let (actual, status) = bid64_from_string("0.00003E-02");
+  assert_eq!(0x30e0000000000003, actual.w);
+  assert!(status.is_empty());
+}
+
+#[test]
+fn test_bid32_from_string() {
+  let (actual, status) = bid32_from_string("0.00003E-02");
+  assert_eq!(0x2f000003, actual.w);
+  assert!(status.is_empty());
+}
+
+#[test]
+fn test_try_number_from_string() {
+  assert!((Ok(Number::Finite(false, 0, 3, -7)) == try_number_from_string("0.00003E-02")));
+  let err = try_number_from_string("0.00003E").err().unwrap();
+  assert_eq!(ParseErrorKind::MissingExponentDigits, err.kind());
+  assert_eq!(8, err.offset());
+  assert_eq!("missing exponent digits at offset 8", err.to_string());
+}
+
+#[test]
+fn test_try_bid128_from_string() {
+  let (actual, status) = try_bid128_from_string("0.00003E-02").unwrap();
+  assert_eq!(0x3032000000000000, actual.w[1]);
+  assert_eq!(0x0000000000000003, actual.w[0]);
+  assert!(status.is_empty());
+  let err = try_bid128_from_string("0.00003E-02x").err().unwrap();
+  assert_eq!(ParseErrorKind::UnexpectedCharacter, err.kind());
+  assert_eq!(11, err.offset());
+  assert_eq!("unexpected character at offset 11", err.to_string());
+}
+
+#[test]
+fn test_bid128_from_str() {
+  let actual: Bid128 = "0.00003E-02".parse().unwrap();
+  assert_eq!(0x3032000000000000, actual.w[1]);
+  assert_eq!(0x0000000000000003, actual.w[0]);
+  let actual = Bid128::try_from("-inf").unwrap();
+  assert_eq!(0xf800000000000000, actual.w[1]);
+  assert!(matches!("1e-7000".parse::<Bid128>(), Err(FromStrError::Underflow)));
+  assert!(matches!("-1e7000".parse::<Bid128>(), Err(FromStrError::Overflow)));
+  match "1.0.0".parse::<Bid128>() {
+    Err(FromStrError::Syntax(err)) => assert_eq!(3, err.offset()),
+    _ => panic!(),
+  }
+  let policy = RangePolicy {
+    reject_overflow: false,
+    ..RangePolicy::default()
+  };
+  let actual = Bid128::from_str_with_policy("1e7000", policy).unwrap();
+  assert_eq!(0x7800000000000000, actual.w[1]);
+  assert!(matches!(
+    Bid128::from_str_with_policy("1e-7000", policy),
+    Err(FromStrError::Underflow)
+  ));
+}
+
+#[test]
+fn test_number_from_str() {
+  assert!((Ok(Number::Finite(false, 0, 3, -7)) == "0.00003E-02".parse::<Number>()));
+  assert!((Ok(Number::Infinite(true)) == Number::try_from("-inf")));
+  assert!(matches!("x".parse::<Number>(), Err(FromStrError::Syntax(_))));
+  assert_eq!(
+    "invalid number: unexpected character at offset 0",
+    "x".parse::<Number>().err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn test_from_string_prefix() {
+  let input = "1.5e3,-2,x";
+  let (value, status, consumed) = bid128_from_string_prefix(input).unwrap();
+  assert_eq!("1.5E+3", value.to_string());
+  assert!(status.is_empty());
+  assert_eq!(5, consumed);
+  let rest = &input[consumed + 1..];
+  assert!((Ok((Number::Finite(true, 0, 2, 0), 2)) == number_from_string_prefix(rest)));
+  let err = number_from_string_prefix(&rest[3..]).err().unwrap();
+  assert_eq!(ParseErrorKind::UnexpectedCharacter, err.kind());
+}
+
+#[test]
+fn test_from_bytes() {
+  let (value, status) = bid128_from_bytes(b"0.00003E-02");
+  assert_eq!([0x0000000000000003, 0x3032000000000000], value.w);
+  assert!(status.is_empty());
+  assert!((Number::Finite(false, 0, 3, -7) == number_from_bytes(b"0.00003E-02")));
+}
+
+#[test]
+fn test_from_string_with_options() {
+  let options = ParseOptions {
+    group_separator: Some(','),
+    strict_grouping: true,
+    ..Default::default()
+  };
+  let (value, status) = bid128_from_string_with_options("-1,234,567.5", Rounding::ToNearest, &options).unwrap();
+  assert_eq!("-1234567.5", value.to_string());
+  assert!(status.is_empty());
+  assert!((Ok(Number::Finite(false, 0, 1000, 0)) == number_from_string_with_options("1,000", &options)));
+  let err = number_from_string_with_options("10,00", &options).err().unwrap();
+  assert_eq!(ParseErrorKind::InvalidGrouping, err.kind());
+  let options = ParseOptions::new()
+    .with_decimal_mark('\u{66B}')
+    .with_group_separator('\u{66C}')
+    .with_strict_grouping(true);
+  assert!((Ok(Number::Finite(false, 0, 123456, -2)) == number_from_string_with_options("1٬234٫56", &options)));
+}
+
+#[test]
+fn test_parse_options_builder() {
+  const OPTIONS: ParseOptions = ParseOptions::new()
+    .with_whitespace(WhitespacePolicy::TRIM)
+    .with_case_sensitive(true)
+    .with_ignore_trailing(true);
+  assert!((Ok(Number::Infinite(true)) == number_from_string_with_options(" -Infinity ", &OPTIONS)));
+  assert!((Ok(Number::Finite(false, 0, 15, -1)) == number_from_string_with_options("1.5 m", &OPTIONS)));
+  assert!(number_from_string_with_options("infinity", &OPTIONS).is_err());
+}
+
+#[test]
+fn test_binary_conversions() {
+  let (value, status) = bid128_from_string("-1.5e-3");
+  assert!(status.is_empty());
+  assert_eq!((-0.0015, Status::INEXACT), value.to_f64_rnd(Rounding::ToNearest));
+  assert_eq!((-0.0015, Status::INEXACT), value.to_f32_rnd(Rounding::ToNearest));
+  let (value, status) = Bid128::from_f64_rnd(-0.0625, Rounding::ToNearest);
+  assert_eq!("-0.0625", value.to_string());
+  assert!(status.is_empty());
+  assert_eq!("0.25", Bid128::try_from(0.25_f32).unwrap().to_string());
+  assert_eq!(
+    Some(Status::INEXACT),
+    Bid128::try_from(0.1_f64).err().map(|err| err.status())
+  );
+  let exact = || -> Result<Bid128, Box<dyn std::error::Error>> { Ok(Bid128::try_from(0.1_f64)?) };
+  assert_eq!(
+    Some("number not exact".to_string()),
+    exact().err().map(|err| err.to_string())
+  );
+  let err = Bid128::try_from(f64::from_bits(0x7ff4000000000000)).err().unwrap();
+  assert!(err.status().is_invalid());
+  assert_eq!("signaling NaN can not be converted", err.to_string());
+}
+
+#[test]
+fn test_binary_from_string() {
+  assert_eq!((0.1, Status::INEXACT), f64_from_string_rnd("0.1", Rounding::ToNearest));
+  assert_eq!(
+    (0.099999994, Status::INEXACT),
+    f32_from_string_rnd("0.1", Rounding::ToZero)
+  );
+  assert_eq!((-2.5, Status::NONE), f64_from_string_rnd("-25e-1", Rounding::Up));
+  let (value, status) = f64_from_string_rnd("1e400", Rounding::ToNearest);
+  assert_eq!(f64::INFINITY, value);
+  assert!(status.is_overflow());
+  let (value, status) = f64_from_string_rnd("x", Rounding::ToNearest);
+  assert!(value.is_nan());
+  assert!(status.is_invalid());
+}
+
+#[test]
+fn test_integer_conversions() {
+  let (value, _) = bid128_from_string("-12.5");
+  assert_eq!((-12, Status::INEXACT), value.to_i64_rnd(Rounding::ToNearest));
+  assert_eq!((-13, Status::INEXACT), value.to_i128_rnd(Rounding::TiesAway));
+  assert_eq!((1 << 63, Status::INVALID), value.to_u64_rnd(Rounding::ToZero));
+  assert_eq!(Err(Status::INEXACT), value.to_i64_exact());
+  assert_eq!(Ok(42), Bid128::from(42_i64).to_u64_exact());
+  assert_eq!("-7", Bid128::from(-7_i64).to_string());
+  assert_eq!("1000000000000000000000", Bid128::from(10_u128.pow(21)).to_string());
+}
+
+#[test]
+fn test_accessors() {
+  let (value, _) = bid128_from_string("-12.50");
+  assert!(value.is_sign_negative());
+  assert_eq!(1250, value.coefficient());
+  assert_eq!(-2, value.exponent());
+  assert!(value.is_finite() && value.is_canonical() && !value.is_zero());
+  assert_eq!(Class::NegativeNormal, value.class());
+  let value = Bid128::from_parts(true, 1250, -2).unwrap();
+  assert_eq!("-12.50", value.to_string());
+  let (value, _) = bid128_from_string("sNaN");
+  assert!(value.is_nan() && value.is_snan() && !value.is_infinite());
+}
+
+#[test]
+fn test_comparisons() {
+  let parse = |input| bid128_from_string(input).0;
+  assert!(parse("1.0") == parse("1.00"));
+  assert!(parse("-0") == parse("0e10"));
+  assert!(parse("NaN") != parse("NaN"));
+  assert!(parse("-Inf") < parse("-1e6144"));
+  assert_eq!(None, parse("1").partial_cmp(&parse("NaN")));
+  let mut values: Vec<Bid128> = ["NaN", "1.0", "-0", "1.00", "-Inf", "0", "-NaN"]
+    .into_iter()
+    .map(parse)
+    .collect();
+  values.sort_by(Bid128::total_cmp);
+  let sorted: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+  assert_eq!(vec!["-NaN", "-Infinity", "-0", "0", "1.00", "1.0", "NaN"], sorted);
+  let state = RandomState::new();
+  assert_eq!(state.hash_one(parse("1.0")), state.hash_one(parse("100e-2")));
+}
+
+#[test]
+fn test_add_sub() {
+  let (a, _) = bid128_from_string("1.10");
+  let (b, _) = bid128_from_string("2.205");
+  assert_eq!("3.305", (a + b).to_string());
+  assert_eq!("-1.105", (a - b).to_string());
+  let (sum, status) = a.add_rnd(&b, Rounding::ToNearest);
+  assert_eq!("3.305", sum.to_string());
+  assert!(status.is_empty());
+  let (c, _) = bid128_from_string("-1.10");
+  assert_eq!("0.00", (a + c).to_string());
+  assert_eq!("-0.00", a.add_rnd(&c, Rounding::Down).0.to_string());
+  let (inf, _) = bid128_from_string("Inf");
+  let (nan, status) = inf.sub_rnd(&inf, Rounding::ToNearest);
+  assert!(nan.is_nan());
+  assert!(status.is_invalid());
+  let (max, _) = bid128_from_string("9.999999999999999999999999999999999e6144");
+  let (sum, status) = max.add_rnd(&max, Rounding::ToZero);
+  assert_eq!(max.w, sum.w);
+  assert_eq!(Status::OVERFLOW | Status::INEXACT, status);
+}
+
+#[test]
+fn test_mul() {
+  let (price, _) = bid128_from_string("19.99");
+  let (quantity, _) = bid128_from_string("-3");
+  assert_eq!("-59.97", (price * quantity).to_string());
+  let (a, _) = bid128_from_string("1e-6100");
+  let (product, status) = a.mul_rnd(&a, Rounding::ToNearest);
+  assert!(product.is_zero());
+  assert_eq!(Status::UNDERFLOW | Status::INEXACT, status);
+  let (inf, _) = bid128_from_string("-Inf");
+  let (zero, _) = bid128_from_string("0");
+  let (nan, status) = inf.mul_rnd(&zero, Rounding::ToNearest);
+  assert!(nan.is_nan());
+  assert!(status.is_invalid());
+}
+
+#[test]
+fn test_div() {
+  let (total, _) = bid128_from_string("100");
+  let (parts, _) = bid128_from_string("8");
+  assert_eq!("12.5", (total / parts).to_string());
+  let (quotient, status) = total.div_rnd(&parts, Rounding::ToNearest);
+  assert_eq!("12.5", quotient.to_string());
+  assert!(status.is_empty());
+  let (zero, _) = bid128_from_string("0");
+  let (infinity, status) = total.div_rnd(&zero, Rounding::ToNearest);
+  assert!(infinity.is_infinite());
+  assert_eq!(Status::ZERO_DIVIDE, status);
+  let (nan, status) = zero.div_rnd(&zero, Rounding::ToNearest);
+  assert!(nan.is_nan());
+  assert_eq!(Status::INVALID, status);
+}
+
+#[test]
+fn test_remainders() {
+  let (a, _) = bid128_from_string("17");
+  let (b, _) = bid128_from_string("5");
+  assert_eq!("3", a.divide_integer(&b).0.to_string());
+  assert_eq!("2", a.rem(&b).0.to_string());
+  assert_eq!("2", a.remainder(&b).0.to_string());
+  let (b, _) = bid128_from_string("6");
+  assert_eq!("5", a.rem(&b).0.to_string());
+  assert_eq!("-1", a.remainder(&b).0.to_string());
+  let (a, _) = bid128_from_string("1e40");
+  let (b, _) = bid128_from_string("3");
+  let (nan, status) = a.divide_integer(&b);
+  assert!(nan.is_nan());
+  assert_eq!(Status::INVALID, status);
+  let (remainder, status) = a.remainder(&b);
+  assert_eq!("1", remainder.to_string());
+  assert!(status.is_empty());
+}
+
+#[test]
+fn test_fma() {
+  let (rate, _) = bid128_from_string("1.05");
+  let (balance, _) = bid128_from_string("1000.00");
+  let (deposit, _) = bid128_from_string("100.00");
+  let (result, status) = balance.fma_rnd(&rate, &deposit, Rounding::ToNearest);
+  assert_eq!("1150.0000", result.to_string());
+  assert!(status.is_empty());
+  let (a, _) = bid128_from_string("1.000000000000000000000000000000001");
+  let (c, _) = bid128_from_string("-1");
+  let (fused, status) = a.fma_rnd(&a, &c, Rounding::ToNearest);
+  assert_eq!("2.000000000000000000000000000000001E-33", fused.to_string());
+  assert!(status.is_empty());
+  assert_eq!("2E-33", (a * a + c).to_string());
+}
+
+#[test]
+fn test_sqrt() {
+  let (a, _) = bid128_from_string("0.0144");
+  let (root, status) = a.sqrt_rnd(Rounding::ToNearest);
+  assert_eq!("0.12", root.to_string());
+  assert!(status.is_empty());
+  let (a, _) = bid128_from_string("3");
+  let (down, _) = a.sqrt_rnd(Rounding::Down);
+  let (up, status) = a.sqrt_rnd(Rounding::Up);
+  assert_eq!("1.732050807568877293527446341505872", down.to_string());
+  assert_eq!("1.732050807568877293527446341505873", up.to_string());
+  assert!(status.is_inexact());
+  let (a, _) = bid128_from_string("-1");
+  let (nan, status) = a.sqrt_rnd(Rounding::ToNearest);
+  assert!(nan.is_nan());
+  assert!(status.is_invalid());
+}
+
+#[test]
+fn test_quantize() {
+  let (cents, _) = bid128_from_string("0.01");
+  let (amount, _) = bid128_from_string("1234.5678");
+  let (rounded, status) = amount.quantize(&cents, Rounding::ToNearest);
+  assert_eq!("1234.57", rounded.to_string());
+  assert!(status.is_inexact());
+  assert!(rounded.same_quantum(&cents));
+  let (truncated, _) = amount.rescale(-2, Rounding::ToZero);
+  assert_eq!("1234.56", truncated.to_string());
+  let (huge, _) = bid128_from_string("1e33");
+  let (nan, status) = huge.quantize(&cents, Rounding::ToNearest);
+  assert!(nan.is_nan());
+  assert!(status.is_invalid());
+}
+
+#[test]
+fn test_round_to_integral() {
+  let value = |s: &str| bid128_from_string(s).0;
+  assert_eq!("2", value("2.7").floor().to_string());
+  assert_eq!("-3", value("-2.1").floor().to_string());
+  assert_eq!("3", value("2.1").ceil().to_string());
+  assert_eq!("-2", value("-2.7").ceil().to_string());
+  assert_eq!("-2", value("-2.7").trunc().to_string());
+  assert_eq!("3", value("2.5").round().to_string());
+  assert_eq!("-3", value("-2.5").round().to_string());
+  assert_eq!("-0", value("-0.4").round().to_string());
+  assert_eq!("1.2E+5", value("1.2e5").floor().to_string());
+  assert!(value("-Inf").ceil().is_infinite());
+  let (result, status) = value("2.5").round_to_integral(Rounding::ToNearest);
+  assert_eq!("2", result.to_string());
+  assert!(status.is_empty());
+  let (result, status) = value("2.5").round_to_integral_exact(Rounding::ToNearest);
+  assert_eq!("2", result.to_string());
+  assert_eq!(Status::INEXACT, status);
+  let (result, status) = value("sNaN").round_to_integral(Rounding::ToNearest);
+  assert!(result.is_nan() && !result.is_snan());
+  assert_eq!(Status::INVALID, status);
 }